@@ -97,6 +97,35 @@ bench! {
     fmt_cmp_dyn_04_16_digits;
 }
 
+bench! {
+    fmt_cmp::cmp::CmpDisplay::cmp_display;
+    cmp_display_01_digit_eq; cmp_display_01_digit_ne;
+    cmp_display_04_digits_eq; cmp_display_04_digits_ne; cmp_display_04_digits_approxeq;
+    cmp_display_16_digits_eq; cmp_display_16_digits_ne; cmp_display_16_digits_approxeq;
+    cmp_display_04_16_digits;
+}
+
+#[bench]
+fn fmt_cmp_dyn_first_byte_ne(b: &mut Bencher) {
+    // Same length but differing in the first digit, so that the first chunks decide the result.
+    let (lhs, rhs) = test::black_box::<(&dyn Display, &dyn Display)>((&D16L, &D16R));
+    b.iter(|| (fmt_cmp::cmp(lhs, rhs), fmt_cmp::eq(lhs, rhs)))
+}
+
+#[bench]
+fn fmt_cmp_eq_16_digits(b: &mut Bencher) {
+    let (lhs, rhs) = test::black_box((fmt_cmp::Cmp(D16L), fmt_cmp::Cmp(D16A)));
+    b.iter(|| (lhs == lhs, lhs == rhs))
+}
+
+#[bench]
+fn eq_display_16_digits(b: &mut Bencher) {
+    use fmt_cmp::cmp::CmpDisplay;
+
+    let (lhs, rhs) = test::black_box((fmt_cmp::Cmp(D16L), fmt_cmp::Cmp(D16A)));
+    b.iter(|| (lhs.eq_display(&lhs), lhs.eq_display(&rhs)))
+}
+
 bench! {
     |&lhs, &rhs| fmt_cmp::cmp_int(lhs, rhs, 10);
     cmp_int_01_digit_eq; cmp_int_01_digit_ne;
@@ -134,3 +163,75 @@ fn cmp_hex_int(b: &mut Bencher) {
         )
     })
 }
+
+#[bench]
+fn cmp_hex_int_dyn_radix(b: &mut Bencher) {
+    let (lhs, rhs, radix) = test::black_box((0xfedcba987654321_u64, 0x123456789abcdef_u64, 16));
+    b.iter(|| {
+        (
+            fmt_cmp::cmp_int(lhs, rhs, radix),
+            fmt_cmp::cmp_int(rhs, lhs, radix),
+        )
+    })
+}
+
+#[bench]
+fn cmp_hex_radix(b: &mut Bencher) {
+    use fmt_cmp::int::{cmp_radix, Hex};
+
+    let (lhs, rhs) = test::black_box((0xfedcba987654321_u64, 0x123456789abcdef_u64));
+    b.iter(|| (cmp_radix::<Hex, _>(lhs, rhs), cmp_radix::<Hex, _>(rhs, lhs)))
+}
+
+#[bench]
+fn cmp_hex(b: &mut Bencher) {
+    let (lhs, rhs) = test::black_box((0xfedcba987654321_u64, 0x123456789abcdef_u64));
+    b.iter(|| (fmt_cmp::cmp_hex(lhs, rhs), fmt_cmp::cmp_hex(rhs, lhs)))
+}
+
+/// Returns 100k pseudo-random `u64` values with various numbers of digits.
+fn shuffled() -> Vec<u64> {
+    let mut x = 0x2545_f491_4f6c_dd1d_u64;
+    (0..100_000)
+        .map(|i| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            x >> (i % 64)
+        })
+        .collect()
+}
+
+#[bench]
+fn sort_by_cmp_dec(b: &mut Bencher) {
+    let values = shuffled();
+    b.iter(|| {
+        let mut values = values.clone();
+        values.sort_unstable_by(|&lhs, &rhs| fmt_cmp::cmp_dec(lhs, rhs));
+        values
+    })
+}
+
+/// Like `sort_by_cmp_dec`, but every value has 6 digits like IDs, so that no alignment is needed.
+#[bench]
+fn sort_by_cmp_dec_same_digits(b: &mut Bencher) {
+    let values: Vec<u64> = shuffled()
+        .into_iter()
+        .map(|x| 100_000 + x % 900_000)
+        .collect();
+    b.iter(|| {
+        let mut values = values.clone();
+        values.sort_unstable_by(|&lhs, &rhs| fmt_cmp::cmp_dec(lhs, rhs));
+        values
+    })
+}
+
+#[bench]
+fn sort_dec(b: &mut Bencher) {
+    let values = shuffled();
+    b.iter(|| {
+        let mut values = values.clone();
+        fmt_cmp::int::sort_dec(&mut values);
+        values
+    })
+}
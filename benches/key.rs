@@ -0,0 +1,38 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Formatter};
+use test::Bencher;
+
+/// A value with an expensive `Display` implementation.
+#[derive(Clone, Copy)]
+struct Expensive(u64);
+
+impl Display for Expensive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for i in 0..16 {
+            write!(f, "{:020}", self.0.rotate_left(i))?;
+        }
+        Ok(())
+    }
+}
+
+fn values() -> impl Iterator<Item = Expensive> {
+    (0..1_000_u64).map(|i| Expensive(i.wrapping_mul(0x9e37_79b9_7f4a_7c15)))
+}
+
+#[bench]
+fn btree_set_cmp(b: &mut Bencher) {
+    b.iter(|| values().map(fmt_cmp::Cmp).collect::<BTreeSet<_>>())
+}
+
+#[bench]
+fn btree_set_cmp_key(b: &mut Bencher) {
+    b.iter(|| {
+        values()
+            .map(fmt_cmp::cmp::CmpKey::new)
+            .collect::<BTreeSet<_>>()
+    })
+}
@@ -0,0 +1,228 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{self, Display, Formatter, Write};
+use std::hash::Hasher;
+use test::{black_box, Bencher};
+
+/// Writes the string in chunks of 64 bytes.
+struct Chunked<'a>(&'a str);
+
+impl Display for Chunked<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0
+            .as_bytes()
+            .chunks(64)
+            .try_for_each(|chunk| f.write_str(std::str::from_utf8(chunk).unwrap()))
+    }
+}
+
+/// Writes the string one byte (`char`) at a time, which is the worst case for the overhead of the
+/// streaming comparison.
+struct Bytewise<'a>(&'a str);
+
+impl Display for Bytewise<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.chars().try_for_each(|c| f.write_char(c))
+    }
+}
+
+/// Returns two equal 4 KiB strings.
+fn long_equal() -> (String, String) {
+    let s = "0123456789abcdef".repeat(256);
+    (s.clone(), s)
+}
+
+/// Returns two 4 KiB strings that differ only in the first byte.
+fn long_first_byte() -> (String, String) {
+    let suffix = "0123456789abcdef".repeat(256);
+    ("0".to_owned() + &suffix, "1".to_owned() + &suffix)
+}
+
+/// Returns two 4 KiB strings that differ only in the last byte.
+fn long_common_prefix() -> (String, String) {
+    let prefix = "0123456789abcdef".repeat(256);
+    (prefix.clone() + "0", prefix + "1")
+}
+
+#[bench]
+fn cmp_long_common_prefix(b: &mut Bencher) {
+    let (x, y) = long_common_prefix();
+    b.iter(|| fmt_cmp::cmp(black_box(&*x), black_box(&*y)))
+}
+
+#[bench]
+fn cmp_long_common_prefix_chunked(b: &mut Bencher) {
+    let (x, y) = long_common_prefix();
+    let (x, y) = (Chunked(&x), Chunked(&y));
+    b.iter(|| fmt_cmp::cmp(black_box(&x), black_box(&y)))
+}
+
+#[bench]
+fn str_cmp_long_common_prefix(b: &mut Bencher) {
+    let (x, y) = long_common_prefix();
+    b.iter(|| black_box(&*x).cmp(black_box(&*y)))
+}
+
+/// Returns two 4 KiB strings where one is a prefix of the other.
+fn long_prefix() -> (String, String) {
+    let prefix = "0123456789abcdef".repeat(256);
+    (prefix.clone(), prefix + "0")
+}
+
+#[bench]
+fn eq_long_common_prefix(b: &mut Bencher) {
+    let (x, y) = long_common_prefix();
+    b.iter(|| fmt_cmp::eq(black_box(&*x), black_box(&*y)))
+}
+
+#[bench]
+fn eq_long_common_prefix_chunked(b: &mut Bencher) {
+    let (x, y) = long_common_prefix();
+    let (x, y) = (Chunked(&x), Chunked(&y));
+    b.iter(|| fmt_cmp::eq(black_box(&x), black_box(&y)))
+}
+
+#[bench]
+fn eq_long_prefix(b: &mut Bencher) {
+    let (x, y) = long_prefix();
+    b.iter(|| {
+        (
+            fmt_cmp::eq(black_box(&*x), black_box(&*y)),
+            fmt_cmp::eq(black_box(&*y), black_box(&*x)),
+        )
+    })
+}
+
+#[bench]
+fn eq_long_prefix_chunked(b: &mut Bencher) {
+    let (x, y) = long_prefix();
+    let (x, y) = (Chunked(&x), Chunked(&y));
+    b.iter(|| {
+        (
+            fmt_cmp::eq(black_box(&x), black_box(&y)),
+            fmt_cmp::eq(black_box(&y), black_box(&x)),
+        )
+    })
+}
+
+macro_rules! bench {
+    (
+        $cmp:expr;
+        $(#[$attr:meta])*
+        $name_equal:ident; $name_first_byte:ident; $name_last_byte:ident; $name_prefix:ident;
+    ) => {
+        bench! { @fn $(#[$attr])* $name_equal(long_equal) = $cmp }
+        bench! { @fn $(#[$attr])* $name_first_byte(long_first_byte) = $cmp }
+        bench! { @fn $(#[$attr])* $name_last_byte(long_common_prefix) = $cmp }
+        bench! { @fn $(#[$attr])* $name_prefix(long_prefix) = $cmp }
+    };
+    (@fn $(#[$attr:meta])* $name:ident($input:expr) = $cmp:expr) => {
+        $(#[$attr])*
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            // Take `$cmp` as `impl Fn` so that type inference works on closure arguments.
+            fn run<R>(x: &str, y: &str, cmp: impl Fn(&str, &str) -> R) -> (R, R) {
+                let (x, y) = black_box((x, y));
+                (cmp(x, y), cmp(y, x))
+            }
+            let (x, y) = $input();
+            b.iter(|| run(&x, &y, $cmp));
+        }
+    };
+}
+
+bench! {
+    |x, y| x.cmp(y);
+    str_cmp_equal; str_cmp_first_byte; str_cmp_last_byte; str_cmp_prefix;
+}
+
+bench! {
+    |x, y| x.to_string().cmp(&y.to_string());
+    to_string_cmp_equal; to_string_cmp_first_byte; to_string_cmp_last_byte; to_string_cmp_prefix;
+}
+
+bench! {
+    |x, y| Bytewise(x).to_string().cmp(&Bytewise(y).to_string());
+    to_string_bytewise_cmp_equal; to_string_bytewise_cmp_first_byte;
+    to_string_bytewise_cmp_last_byte; to_string_bytewise_cmp_prefix;
+}
+
+bench! {
+    fmt_cmp::cmp;
+    cmp_equal; cmp_first_byte; cmp_last_byte; cmp_prefix;
+}
+
+bench! {
+    |x, y| fmt_cmp::cmp(&Bytewise(x), &Bytewise(y));
+    cmp_bytewise_equal; cmp_bytewise_first_byte; cmp_bytewise_last_byte; cmp_bytewise_prefix;
+}
+
+bench! {
+    fmt_cmp::eq;
+    eq_equal; eq_first_byte; eq_last_byte; eq_prefix;
+}
+
+bench! {
+    |x, y| fmt_cmp::eq(&Bytewise(x), &Bytewise(y));
+    eq_bytewise_equal; eq_bytewise_first_byte; eq_bytewise_last_byte; eq_bytewise_prefix;
+}
+
+/// Hashes the `Display` representation by feeding each chunk to the hasher as it is, for
+/// comparison with the blocks that `fmt_cmp::hash` feeds.
+fn naive_hash<T: Display + ?Sized, H: Hasher>(value: &T, hasher: &mut H) {
+    struct Adapter<'a, H>(&'a mut H);
+
+    impl<H: Hasher> Write for Adapter<'_, H> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0.write(s.as_bytes());
+            Ok(())
+        }
+    }
+
+    write!(Adapter(hasher), "{}", value).unwrap();
+    hasher.write_u8(0xff);
+}
+
+#[bench]
+fn hash_long(b: &mut Bencher) {
+    let (x, _) = long_equal();
+    b.iter(|| {
+        let mut hasher = DefaultHasher::new();
+        fmt_cmp::hash(black_box(&*x), &mut hasher);
+        hasher.finish()
+    })
+}
+
+#[bench]
+fn hash_long_bytewise(b: &mut Bencher) {
+    let (x, _) = long_equal();
+    b.iter(|| {
+        let mut hasher = DefaultHasher::new();
+        fmt_cmp::hash(&Bytewise(black_box(&x)), &mut hasher);
+        hasher.finish()
+    })
+}
+
+#[bench]
+fn naive_hash_long_bytewise(b: &mut Bencher) {
+    let (x, _) = long_equal();
+    b.iter(|| {
+        let mut hasher = DefaultHasher::new();
+        naive_hash(&Bytewise(black_box(&x)), &mut hasher);
+        hasher.finish()
+    })
+}
+
+#[bench]
+fn str_hash_long(b: &mut Bencher) {
+    let (x, _) = long_equal();
+    b.iter(|| {
+        let mut hasher = DefaultHasher::new();
+        hasher.write(black_box(x.as_bytes()));
+        hasher.write_u8(0xff);
+        hasher.finish()
+    })
+}
@@ -0,0 +1,30 @@
+use std::env;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=RUSTC");
+
+    let minor = match rustc_minor_version() {
+        Some(minor) => minor,
+        None => return,
+    };
+
+    // `core::net` is stable since Rust 1.77.
+    if minor >= 77 {
+        println!("cargo:rustc-cfg=fmt_cmp_core_net");
+    }
+}
+
+/// Returns the minor version of the compiler, or `None` if it cannot be determined.
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    // e.g. `rustc 1.77.0 (aedd173a2 2024-03-17)`
+    let mut parts = version.split_whitespace().nth(1)?.split('.');
+    if parts.next()? != "1" {
+        return None;
+    }
+    parts.next()?.parse().ok()
+}
@@ -0,0 +1,108 @@
+//! Derive macros for [`fmt-cmp`](https://docs.rs/fmt-cmp) crate.
+//!
+//! You should use the macros through the re-exports in `fmt_cmp` crate, which are available with
+//! `derive` feature of the crate.
+
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, DeriveInput, GenericParam};
+
+/// Derives `fmt_cmp::FmtEq` marker trait.
+///
+/// The generated implementation requires the type to implement `Display` and `Eq`, so forgetting
+/// either of them results in a compile error. If the type has type parameters, each of them is
+/// bound by `FmtEq`.
+///
+/// Note that the macro cannot verify the semantic contract of the trait, i.e., that the
+/// equivalence relation of the type matches that of its `Display` representation. It is still
+/// your responsibility to uphold the contract.
+///
+/// ## Example
+///
+/// ```
+/// use std::fmt::{self, Display, Formatter};
+///
+/// #[derive(PartialEq, Eq, fmt_cmp::FmtEq)]
+/// struct Id(u32);
+///
+/// impl Display for Id {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         self.0.fmt(f)
+///     }
+/// }
+/// ```
+///
+/// A type without `Display` implementation is rejected:
+///
+/// ```compile_fail
+/// #[derive(PartialEq, Eq, fmt_cmp::FmtEq)]
+/// struct Id(u32);
+/// ```
+#[proc_macro_derive(FmtEq)]
+pub fn derive_fmt_eq(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(input, quote!(::fmt_cmp::FmtEq))
+}
+
+/// Derives `fmt_cmp::FmtOrd` marker trait.
+///
+/// The generated implementation requires the type to implement `Display`, `Ord` and `FmtEq`, so
+/// forgetting any of them results in a compile error. If the type has type parameters, each of
+/// them is bound by `FmtOrd`.
+///
+/// Note that the macro cannot verify the semantic contract of the trait, i.e., that the ordering
+/// of the type matches that of its `Display` representation. It is still your responsibility to
+/// uphold the contract.
+///
+/// ## Example
+///
+/// ```
+/// use std::fmt::{self, Display, Formatter};
+///
+/// #[derive(PartialEq, Eq, PartialOrd, Ord, fmt_cmp::FmtEq, fmt_cmp::FmtOrd)]
+/// struct Name(String);
+///
+/// impl Display for Name {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         self.0.fmt(f)
+///     }
+/// }
+/// ```
+///
+/// A type without `FmtEq` implementation is rejected:
+///
+/// ```compile_fail
+/// use std::fmt::{self, Display, Formatter};
+///
+/// #[derive(PartialEq, Eq, PartialOrd, Ord, fmt_cmp::FmtOrd)]
+/// struct Name(String);
+///
+/// impl Display for Name {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         self.0.fmt(f)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(FmtOrd)]
+pub fn derive_fmt_ord(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(input, quote!(::fmt_cmp::FmtOrd))
+}
+
+fn derive(mut input: DeriveInput, trait_path: TokenStream2) -> TokenStream {
+    for param in &mut input.generics.params {
+        if let GenericParam::Type(ref mut param) = *param {
+            param.bounds.push(parse_quote!(#trait_path));
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let expanded = quote! {
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {}
+    };
+    expanded.into()
+}
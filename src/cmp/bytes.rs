@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Write};
+
+use super::Cmp;
+
+/// A wrapper type that compares a byte slice with the `Display` representations of values.
+///
+/// This compares with [`Cmp<T>`] as if the byte slice were the `Display` representation of
+/// a value, with [`eq_bytes`] and [`cmp_bytes`] functions. The byte slice is not required to be
+/// valid UTF-8, in which case it is compared byte-wise all the same.
+///
+/// Comparison between `CmpBytes` values is the same as that of `[u8]`.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::CmpBytes;
+/// use fmt_cmp::Cmp;
+///
+/// assert_eq!(CmpBytes(b"2A"), Cmp(format_args!("{:X}", 42)));
+/// assert!(CmpBytes(b"42") > Cmp(240));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CmpBytes<'a>(pub &'a [u8]);
+
+impl<T: Display + ?Sized> PartialEq<Cmp<T>> for CmpBytes<'_> {
+    fn eq(&self, other: &Cmp<T>) -> bool {
+        eq_bytes(&other.0, self.0)
+    }
+}
+
+impl<T: Display + ?Sized> PartialEq<CmpBytes<'_>> for Cmp<T> {
+    fn eq(&self, other: &CmpBytes<'_>) -> bool {
+        eq_bytes(&self.0, other.0)
+    }
+}
+
+impl<T: Display + ?Sized> PartialOrd<Cmp<T>> for CmpBytes<'_> {
+    fn partial_cmp(&self, other: &Cmp<T>) -> Option<Ordering> {
+        Some(cmp_bytes(&other.0, self.0).reverse())
+    }
+}
+
+impl<T: Display + ?Sized> PartialOrd<CmpBytes<'_>> for Cmp<T> {
+    fn partial_cmp(&self, other: &CmpBytes<'_>) -> Option<Ordering> {
+        Some(cmp_bytes(&self.0, other.0))
+    }
+}
+
+/// Tests whether the `Display` representation of a value equals a byte slice.
+///
+/// This yields the same result as `value.to_string().as_bytes() == bytes` without heap
+/// allocation.
+///
+/// ## Note
+///
+/// The `Display` implementation may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified return value or might even cause
+/// a panic in a future version.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp::eq_bytes(&format_args!("{:X}", 42), b"2A"));
+/// ```
+#[must_use]
+pub fn eq_bytes<T: Display + ?Sized>(value: &T, bytes: &[u8]) -> bool {
+    cmp_bytes(value, bytes) == Ordering::Equal
+}
+
+/// Compares the `Display` representation of a value with a byte slice.
+///
+/// This yields the same result as `value.to_string().as_bytes().cmp(bytes)` without heap
+/// allocation.
+///
+/// ## Note
+///
+/// The `Display` implementation may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified `Ordering` value or might even cause
+/// a panic in a future version.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp::cmp_bytes(&42, b"240").is_gt());
+/// ```
+#[must_use]
+pub fn cmp_bytes<T: Display + ?Sized>(value: &T, bytes: &[u8]) -> Ordering {
+    struct Adapter<'a> {
+        /// The remaining part of the byte slice.
+        bytes: &'a [u8],
+        ret: Ordering,
+    }
+
+    impl Write for Adapter<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let s = s.as_bytes();
+            let n = s.len().min(self.bytes.len());
+            let (head, rest) = self.bytes.split_at(n);
+            self.ret = s[..n].cmp(head);
+            if self.ret == Ordering::Equal && s.len() > n {
+                // `value` is longer than `bytes`.
+                self.ret = Ordering::Greater;
+            }
+            if self.ret != Ordering::Equal {
+                // Short-circuit by returning an error.
+                return Err(fmt::Error);
+            }
+            self.bytes = rest;
+            Ok(())
+        }
+    }
+
+    let mut adapter = Adapter {
+        bytes,
+        ret: Ordering::Equal,
+    };
+    let _ = write!(adapter, "{}", value);
+    if adapter.ret == Ordering::Equal && !adapter.bytes.is_empty() {
+        // `value` is a proper prefix of `bytes`.
+        Ordering::Less
+    } else {
+        adapter.ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn matches_slice_cmp() {
+        #[track_caller]
+        fn check<T: Display>(value: T, bytes: &[u8]) {
+            let expected = value.to_string().as_bytes().cmp(bytes);
+            assert_eq!(cmp_bytes(&value, bytes), expected);
+            assert_eq!(eq_bytes(&value, bytes), expected == Ordering::Equal);
+            assert_eq!(Cmp(&value).partial_cmp(&CmpBytes(bytes)), Some(expected));
+            assert_eq!(
+                CmpBytes(bytes).partial_cmp(&Cmp(&value)),
+                Some(expected.reverse()),
+                "rev"
+            );
+        }
+
+        check(format_args!("{:X}", 42), b"2A");
+        check(format_args!("{:x}", 42), b"2A");
+        check(format_args!("{}{}", 4, 2), b"42");
+        check(42, b"4");
+        check(42, b"420");
+        check(42, b"240");
+        check("", b"");
+        check("", b"a");
+        check("a", b"");
+        // Not valid UTF-8.
+        check("\u{ff}", b"\xff");
+        check("a", b"a\xff");
+    }
+}
@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+
+use super::{cmp, eq};
+
+/// Compares two values in their `Display` representations, treating each run of ASCII whitespace
+/// as a single space.
+///
+/// This yields the same result as comparing the `to_string()` outputs with every maximal run of
+/// characters satisfying `char::is_ascii_whitespace` replaced with `' '`, without heap allocation.
+/// Leading and trailing runs are collapsed as well, but not removed, so `" foo"` does not compare
+/// equal to `"foo"`.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::cmp_ws_collapsed;
+///
+/// assert!(cmp_ws_collapsed("foo \t bar", "foo bar").is_eq());
+/// // `"foo bar" < "foo!"` although `"foo\tbar" > "foo!"`.
+/// assert!(cmp_ws_collapsed("foo\tbar", "foo!").is_lt());
+/// ```
+#[must_use]
+pub fn cmp_ws_collapsed<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    cmp(&Collapsed(lhs), &Collapsed(rhs))
+}
+
+/// Tests two values for equality in their `Display` representations, treating each run of ASCII
+/// whitespace as a single space.
+///
+/// See [`cmp_ws_collapsed`] for the details.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::eq_ws_collapsed;
+///
+/// assert!(eq_ws_collapsed("foo  bar\n", "foo bar "));
+/// assert!(!eq_ws_collapsed("foo bar", "foobar"));
+/// ```
+#[must_use]
+pub fn eq_ws_collapsed<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> bool {
+    eq(&Collapsed(lhs), &Collapsed(rhs))
+}
+
+/// A `Display` adapter that replaces each run of ASCII whitespace with a single space.
+struct Collapsed<'a, T: ?Sized>(&'a T);
+
+impl<T: Display + ?Sized> Display for Collapsed<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        struct Adapter<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            // Whether the last byte written was whitespace, which may be in a previous chunk.
+            in_whitespace: bool,
+        }
+
+        impl Write for Adapter<'_, '_> {
+            fn write_str(&mut self, mut s: &str) -> fmt::Result {
+                while !s.is_empty() {
+                    if self.in_whitespace {
+                        s = s.trim_start_matches(|c: char| c.is_ascii_whitespace());
+                        self.in_whitespace = s.is_empty();
+                    } else if let Some(i) = s.bytes().position(|b| b.is_ascii_whitespace()) {
+                        self.f.write_str(&s[..i])?;
+                        self.f.write_char(' ')?;
+                        s = &s[i + 1..];
+                        self.in_whitespace = true;
+                    } else {
+                        return self.f.write_str(s);
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        write!(
+            Adapter {
+                f,
+                in_whitespace: false,
+            },
+            "{}",
+            self.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::{String, ToString};
+
+    use super::*;
+    use crate::testing::Chars;
+
+    fn collapse(s: &str) -> String {
+        let mut ret = String::new();
+        for c in s.chars() {
+            if !c.is_ascii_whitespace() {
+                ret.push(c);
+            } else if !ret.ends_with(' ') {
+                ret.push(' ');
+            }
+        }
+        ret
+    }
+
+    #[test]
+    fn matches_collapsed_str_cmp() {
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let (cx, cy) = (collapse(x), collapse(y));
+            let expected = cx.cmp(&cy);
+            assert_eq!(cmp_ws_collapsed(x, y), expected);
+            assert_eq!(cmp_ws_collapsed(y, x), expected.reverse(), "rev");
+            assert_eq!(cmp_ws_collapsed(&Chars(x), &Chars(y)), expected, "chars");
+            assert_eq!(cmp_ws_collapsed(&Chars(x), y), expected, "chars,str");
+            assert_eq!(eq_ws_collapsed(x, y), cx == cy);
+            assert_eq!(eq_ws_collapsed(&Chars(x), y), cx == cy, "chars");
+            assert_eq!(Collapsed(x).to_string(), cx);
+            assert_eq!(Collapsed(&Chars(x)).to_string(), cx, "chars");
+        }
+
+        check("", "");
+        check("a  b", "a b");
+        check("a \t\r\n\x0cb", "a b");
+        check("a b", "ab");
+        check("a\tb", "a!");
+        check("a  b  c", "a b c");
+        check("a  bc", "a b c");
+        // Leading and trailing runs.
+        check("  a", " a");
+        check(" a", "a");
+        check("a \n ", "a ");
+        check("a ", "a");
+        check("   ", " ");
+        check(" ", "");
+        // Non-ASCII whitespace is compared verbatim.
+        check("a\u{3000}b", "a b");
+        check("a \u{a0} b", "a \u{a0} b");
+        check("\x0b", " ");
+        check("é  é", "é é");
+    }
+}
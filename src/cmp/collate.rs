@@ -0,0 +1,265 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+use std::str;
+
+use unicode_normalization::char::{canonical_combining_class, compose, decompose_canonical};
+
+use super::cmp;
+
+/// Maximum number of consecutive non-starters that are normalized correctly.
+///
+/// This is greater than the limit of the [Stream-Safe Text Format] (30), so every stream-safe
+/// text is normalized correctly.
+///
+/// [Stream-Safe Text Format]: <https://www.unicode.org/reports/tr15/#Stream_Safe_Text_Format>
+const MAX_NON_STARTERS: usize = 32;
+
+/// Compares two values in their `Display` representations normalized to the Unicode
+/// Normalization Form C (NFC).
+///
+/// This yields the same result as comparing the NFC forms of `lhs.to_string()` and
+/// `rhs.to_string()` by code points without heap allocation, so canonically equivalent
+/// representations compare equal. Note that this is not a locale-aware collation: the code point
+/// order still puts `"Z"` before `"a"`.
+///
+/// Sequences of more than 32 consecutive non-starters (combining marks) are not normalized
+/// correctly, but such sequences never appear in the [Stream-Safe Text Format].
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// // `"é"` (U+00E9) is canonically equivalent to `"e"` followed by U+0301 COMBINING ACUTE ACCENT.
+/// assert!(fmt_cmp::cmp::cmp_collate("café", "cafe\u{301}").is_eq());
+/// assert!(fmt_cmp::cmp::cmp_collate("café", "cafes").is_gt());
+/// ```
+///
+/// [Stream-Safe Text Format]: <https://www.unicode.org/reports/tr15/#Stream_Safe_Text_Format>
+#[must_use]
+pub fn cmp_collate<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    cmp(&Nfc(lhs), &Nfc(rhs))
+}
+
+/// A `Display` adapter that normalizes the output to NFC.
+struct Nfc<'a, T: ?Sized>(&'a T);
+
+impl<T: Display + ?Sized> Display for Nfc<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut normalizer = Normalizer {
+            f,
+            marks: [(0, '\0'); MAX_NON_STARTERS],
+            marks_len: 0,
+            starter: None,
+            uncomposed: ['\0'; MAX_NON_STARTERS],
+            uncomposed_len: 0,
+            last_ccc: None,
+            out: [0; 64],
+            out_len: 0,
+        };
+        write!(normalizer, "{}", self.0)?;
+        normalizer.finish()
+    }
+}
+
+/// Streaming NFC normalizer, which decomposes the input, reorders the non-starters canonically and
+/// then recomposes the result, like the `nfc()` iterator of `unicode-normalization` crate.
+struct Normalizer<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    /// Decomposed non-starters waiting for the canonical ordering, with their combining classes.
+    marks: [(u8, char); MAX_NON_STARTERS],
+    marks_len: usize,
+    /// The last starter, which may compose with the subsequent characters.
+    starter: Option<char>,
+    /// Characters following `starter` that have not composed with it.
+    uncomposed: [char; MAX_NON_STARTERS],
+    uncomposed_len: usize,
+    /// Combining class of the last character of `uncomposed`.
+    last_ccc: Option<u8>,
+    /// Output buffer to reduce the number of `write_str` calls.
+    out: [u8; 64],
+    out_len: usize,
+}
+
+impl Normalizer<'_, '_> {
+    fn push_decomposed(&mut self, c: char) -> fmt::Result {
+        let ccc = canonical_combining_class(c);
+        if ccc == 0 {
+            self.flush_marks()?;
+            self.compose(c, 0)
+        } else {
+            if self.marks_len == self.marks.len() {
+                self.flush_marks()?;
+            }
+            self.marks[self.marks_len] = (ccc, c);
+            self.marks_len += 1;
+            Ok(())
+        }
+    }
+
+    /// Sorts the pending non-starters by their combining classes and passes them to `compose`.
+    fn flush_marks(&mut self) -> fmt::Result {
+        let marks = &mut self.marks[..self.marks_len];
+        // Stable insertion sort, since `slice::sort_by_key` is unavailable without `alloc`.
+        for i in 1..marks.len() {
+            let mut j = i;
+            while j > 0 && marks[j - 1].0 > marks[j].0 {
+                marks.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+
+        for i in 0..self.marks_len {
+            let (ccc, c) = self.marks[i];
+            self.compose(c, ccc)?;
+        }
+        self.marks_len = 0;
+        Ok(())
+    }
+
+    fn compose(&mut self, c: char, ccc: u8) -> fmt::Result {
+        let starter = if let Some(starter) = self.starter {
+            starter
+        } else if ccc == 0 {
+            self.starter = Some(c);
+            return Ok(());
+        } else {
+            return self.emit(c);
+        };
+
+        // `c` is blocked from `starter` if a character of the same or higher combining class
+        // (including another starter) lies between them.
+        let blocked = self.last_ccc.map_or(false, |last| last >= ccc);
+        if !blocked {
+            if let Some(composed) = compose(starter, c) {
+                self.starter = Some(composed);
+                return Ok(());
+            }
+        }
+
+        if ccc == 0 {
+            self.flush_starter()?;
+            self.starter = Some(c);
+        } else if self.uncomposed_len == self.uncomposed.len() {
+            self.flush_starter()?;
+            self.emit(c)?;
+        } else {
+            self.uncomposed[self.uncomposed_len] = c;
+            self.uncomposed_len += 1;
+            self.last_ccc = Some(ccc);
+        }
+        Ok(())
+    }
+
+    fn flush_starter(&mut self) -> fmt::Result {
+        if let Some(starter) = self.starter.take() {
+            self.emit(starter)?;
+        }
+        for i in 0..self.uncomposed_len {
+            self.emit(self.uncomposed[i])?;
+        }
+        self.uncomposed_len = 0;
+        self.last_ccc = None;
+        Ok(())
+    }
+
+    fn emit(&mut self, c: char) -> fmt::Result {
+        if self.out.len() - self.out_len < c.len_utf8() {
+            self.flush_out()?;
+        }
+        let n = c.encode_utf8(&mut self.out[self.out_len..]).len();
+        self.out_len += n;
+        Ok(())
+    }
+
+    fn flush_out(&mut self) -> fmt::Result {
+        // Safety: `out[..out_len]` consists of whole `char`s encoded by `emit`.
+        let s = unsafe { str::from_utf8_unchecked(&self.out[..self.out_len]) };
+        self.out_len = 0;
+        self.f.write_str(s)
+    }
+
+    fn finish(mut self) -> fmt::Result {
+        self.flush_marks()?;
+        self.flush_starter()?;
+        self.flush_out()
+    }
+}
+
+impl Write for Normalizer<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // A `&str` never splits a `char`, so we can decompose the characters one by one.
+        for c in s.chars() {
+            let mut result = Ok(());
+            decompose_canonical(c, |d| {
+                if result.is_ok() {
+                    result = self.push_decomposed(d);
+                }
+            });
+            result?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::{String, ToString};
+    use unicode_normalization::UnicodeNormalization;
+
+    use super::*;
+    use crate::testing::Chars;
+
+    #[test]
+    fn matches_nfc() {
+        #[track_caller]
+        fn check(s: &str) {
+            let expected: String = s.nfc().collect();
+            assert_eq!(Nfc(s).to_string(), expected);
+            assert_eq!(Nfc(&Chars(s)).to_string(), expected, "chars");
+        }
+
+        check("");
+        check("abc");
+        check("café");
+        check("cafe\u{301}");
+        // U+212B ANGSTROM SIGN, U+00C5 and `"A"` followed by U+030A COMBINING RING ABOVE.
+        check("\u{212b} \u{c5} A\u{30a}");
+        // Non-canonical order of combining marks.
+        check("q\u{307}\u{323} q\u{323}\u{307}");
+        // Blocked composition.
+        check("a\u{328}\u{301} a\u{301}\u{328} e\u{323}\u{302}");
+        // Hangul syllables.
+        check("\u{d55c} \u{1112}\u{1161}\u{11ab} \u{1112}\u{1161} \u{11ab}");
+        // A non-starter at the start.
+        check("\u{301}e\u{301}");
+        // Longer than the output buffer.
+        check(&"cafe\u{301} ".repeat(20));
+        // Up to `MAX_NON_STARTERS` non-starters.
+        check(&("a".to_string() + &"\u{323}\u{301}".repeat(MAX_NON_STARTERS / 2)));
+    }
+
+    #[test]
+    fn combining_mark_equivalence() {
+        #[track_caller]
+        fn check(x: &str, y: &str, expected: Ordering) {
+            assert_eq!(cmp_collate(x, y), expected);
+            assert_eq!(cmp_collate(y, x), expected.reverse(), "rev");
+            assert_eq!(cmp_collate(&Chars(x), &Chars(y)), expected, "chars");
+        }
+
+        check("café", "cafe\u{301}", Ordering::Equal);
+        check("\u{212b}", "A\u{30a}", Ordering::Equal);
+        check("\u{c5}", "A\u{30a}", Ordering::Equal);
+        check("q\u{307}\u{323}", "q\u{323}\u{307}", Ordering::Equal);
+        check("\u{d55c}", "\u{1112}\u{1161}\u{11ab}", Ordering::Equal);
+        check("café", "cafe", Ordering::Greater);
+        check("cafe\u{301}", "cafez", Ordering::Greater);
+        check("Z", "a", Ordering::Less);
+    }
+}
@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use super::Cmp;
+
+/// Types whose `Display` representations can be compared without formatting them.
+///
+/// The [`cmp`](super::cmp()) function relies on specialization to skip formatting for primitive
+/// types, which is only available with the unstable `fmt_cmp_semver_exempt` configuration. This
+/// trait provides the same fast paths on stable Rust for the types known to have them: primitive
+/// integers are compared with [`cmp_dec`](crate::cmp_dec) and string types are compared as `str`.
+///
+/// Implementations must return the same value as `cmp(self, other)` (and `eq(self, other)` for
+/// [`eq_display`](CmpDisplay::eq_display)).
+///
+/// Note that `Cmp<T>`'s `PartialEq` and `Ord` implementations cannot use this trait even if `T`
+/// implements it, because choosing an implementation depending on whether `T` implements a trait
+/// requires specialization. Call the methods of this trait directly to take the fast paths on
+/// stable Rust.
+///
+/// This trait is available with `cmp-display` feature, which is enabled by default.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::CmpDisplay;
+///
+/// assert!(42_u64.cmp_display(&240).is_gt());
+/// assert!("42".cmp_display("240").is_gt());
+/// assert!(42_u64.eq_display(&42));
+/// ```
+pub trait CmpDisplay: Display {
+    /// Compares `self` with `other` in their `Display` representations.
+    fn cmp_display(&self, other: &Self) -> Ordering;
+
+    /// Tests `self` and `other` for equality in their `Display` representations.
+    fn eq_display(&self, other: &Self) -> bool {
+        self.cmp_display(other) == Ordering::Equal
+    }
+}
+
+impl<T: CmpDisplay + ?Sized> CmpDisplay for &T {
+    fn cmp_display(&self, other: &Self) -> Ordering {
+        (**self).cmp_display(*other)
+    }
+
+    fn eq_display(&self, other: &Self) -> bool {
+        (**self).eq_display(*other)
+    }
+}
+
+impl<T: CmpDisplay + ?Sized> CmpDisplay for Cmp<T> {
+    fn cmp_display(&self, other: &Self) -> Ordering {
+        self.0.cmp_display(&other.0)
+    }
+
+    fn eq_display(&self, other: &Self) -> bool {
+        self.0.eq_display(&other.0)
+    }
+}
+
+macro_rules! int_cmp {
+    ($($ty:ty)*) => {$(
+        impl CmpDisplay for $ty {
+            fn cmp_display(&self, other: &Self) -> Ordering {
+                crate::cmp_dec(*self, *other)
+            }
+
+            // Every integer has a unique decimal representation.
+            fn eq_display(&self, other: &Self) -> bool {
+                *self == *other
+            }
+        }
+    )*};
+}
+
+int_cmp! {
+    u8 u16 u32 u64 usize u128
+    i8 i16 i32 i64 isize i128
+}
+
+/// Implements `CmpDisplay` with `Ord` and `Eq` for types whose `Ord` agrees with the `Display`
+/// representations.
+macro_rules! naive_cmp {
+    ($($(#[$attr:meta])* $ty:ty;)*) => {$(
+        $(#[$attr])*
+        impl CmpDisplay for $ty {
+            fn cmp_display(&self, other: &Self) -> Ordering {
+                Ord::cmp(self, other)
+            }
+
+            fn eq_display(&self, other: &Self) -> bool {
+                self == other
+            }
+        }
+    )*};
+}
+
+naive_cmp! {
+    // `"false" < "true"`.
+    bool;
+    // UTF-8 preserves the code point order.
+    char;
+    str;
+    #[cfg(feature = "alloc")]
+    alloc::string::String;
+    #[cfg(feature = "alloc")]
+    alloc::boxed::Box<str>;
+    #[cfg(feature = "alloc")]
+    alloc::borrow::Cow<'_, str>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_cmp() {
+        #[track_caller]
+        fn check<T: CmpDisplay + ?Sized>(x: &T, y: &T) {
+            let expected = super::super::generic::cmp(x, y);
+            assert_eq!(x.cmp_display(y), expected);
+            assert_eq!(y.cmp_display(x), expected.reverse(), "rev");
+            assert_eq!(Cmp(x).cmp_display(&Cmp(y)), expected, "Cmp");
+            let expected = super::super::generic::eq(x, y);
+            assert_eq!(x.eq_display(y), expected, "eq");
+            assert_eq!(y.eq_display(x), expected, "eq,rev");
+            assert_eq!(Cmp(x).eq_display(&Cmp(y)), expected, "eq,Cmp");
+        }
+
+        check(&42_u64, &240);
+        check(&42_u64, &42);
+        check(&0_u8, &10);
+        check(&-1_i32, &-10);
+        check(&-1_i32, &1);
+        check(&i128::min_value(), &i128::max_value());
+        check(&u128::max_value(), &9);
+        check(&false, &true);
+        check(&'a', &'\u{e9}');
+        check(&'\u{ffff}', &'\u{10000}');
+        check("42", "240");
+        check("", "a");
+    }
+}
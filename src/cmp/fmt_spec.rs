@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+
+use super::cmp;
+
+/// Formatting parameters for [`cmp_with_spec`], corresponding to the fill, alignment, width and
+/// precision of a format specifier like `{:*^10.3}`.
+///
+/// The default value corresponds to `{}`, except that it specifies the left alignment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FmtSpec {
+    /// The minimum width of the representation in `char`s, or `None` for no padding.
+    pub width: Option<usize>,
+    /// The character to pad the representation with.
+    pub fill: char,
+    /// The alignment of the representation within `width`.
+    pub align: Align,
+    /// The precision to format the values with, or `None` for the default precision.
+    pub precision: Option<usize>,
+}
+
+impl Default for FmtSpec {
+    fn default() -> Self {
+        FmtSpec {
+            width: None,
+            fill: ' ',
+            align: Align::Left,
+            precision: None,
+        }
+    }
+}
+
+/// The alignment of a representation within [`FmtSpec::width`], like `fmt::Alignment`.
+///
+/// This is a separate type from `fmt::Alignment` since the latter does not implement `Clone`,
+/// `PartialEq` and the like on older compilers that this crate supports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Pads after the representation, like `{:<}`.
+    Left,
+    /// Pads before the representation, like `{:>}`.
+    Right,
+    /// Pads on both sides of the representation, like `{:^}`, putting the extra fill character
+    /// (if any) after it.
+    Center,
+}
+
+impl From<fmt::Alignment> for Align {
+    fn from(align: fmt::Alignment) -> Self {
+        match align {
+            fmt::Alignment::Left => Align::Left,
+            fmt::Alignment::Right => Align::Right,
+            fmt::Alignment::Center => Align::Center,
+        }
+    }
+}
+
+/// Compares two values in their `Display` representations formatted with the given parameters.
+///
+/// The values are formatted with `spec.precision`, and then padded with `spec.fill` up to
+/// `spec.width` according to `spec.align`, like `Formatter::pad` does for strings. For the types
+/// whose `Display` implementations honor the flags like the primitive types do, this yields the
+/// same result as comparing the outputs of `format!` with the corresponding format specifier (e.g.
+/// `{:*>8.2}`), without heap allocation. Note that the padding is applied regardless of whether
+/// the `Display` implementations honor the width.
+///
+/// When `spec.width` is specified, each value is formatted once more to measure its width.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::{cmp_with_spec, Align, FmtSpec};
+///
+/// let spec = FmtSpec {
+///     width: Some(4),
+///     fill: '0',
+///     align: Align::Right,
+///     ..FmtSpec::default()
+/// };
+/// // `"0042" < "0240"`
+/// assert!(cmp_with_spec(&42, &240, spec).is_lt());
+/// // `"42" > "240"`
+/// assert!(cmp_with_spec(&42, &240, FmtSpec::default()).is_gt());
+/// ```
+#[must_use]
+pub fn cmp_with_spec<T: Display + ?Sized, U: Display + ?Sized>(
+    lhs: &T,
+    rhs: &U,
+    spec: FmtSpec,
+) -> Ordering {
+    cmp(&Spec(lhs, &spec), &Spec(rhs, &spec))
+}
+
+/// A `Display` adapter that formats the value with a `FmtSpec`.
+struct Spec<'a, T: ?Sized>(&'a T, &'a FmtSpec);
+
+impl<T: Display + ?Sized> Spec<'_, T> {
+    fn write_value<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match self.1.precision {
+            Some(precision) => write!(w, "{:.*}", precision, self.0),
+            None => write!(w, "{}", self.0),
+        }
+    }
+}
+
+impl<T: Display + ?Sized> Display for Spec<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        struct Counter(usize);
+
+        impl Write for Counter {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += s.chars().count();
+                Ok(())
+            }
+        }
+
+        let width = if let Some(width) = self.1.width {
+            width
+        } else {
+            return self.write_value(f);
+        };
+
+        let mut counter = Counter(0);
+        self.write_value(&mut counter)?;
+        let padding = width.saturating_sub(counter.0);
+        let (pre, post) = match self.1.align {
+            Align::Left => (0, padding),
+            Align::Right => (padding, 0),
+            Align::Center => (padding / 2, (padding + 1) / 2),
+        };
+
+        for _ in 0..pre {
+            f.write_char(self.1.fill)?;
+        }
+        self.write_value(f)?;
+        for _ in 0..post {
+            f.write_char(self.1.fill)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::format;
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn padded_integers() {
+        #[track_caller]
+        fn check(x: i32, y: i32) {
+            let spec = |width, fill, align| FmtSpec {
+                width: Some(width),
+                fill,
+                align,
+                precision: None,
+            };
+            for &width in &[0, 1, 3, 4, 6, 12] {
+                let w = width;
+                let cases = [
+                    (
+                        spec(w, ' ', Align::Left),
+                        format!("{:<w$}", x, w = w),
+                        format!("{:<w$}", y, w = w),
+                    ),
+                    (
+                        spec(w, '0', Align::Right),
+                        format!("{:0>w$}", x, w = w),
+                        format!("{:0>w$}", y, w = w),
+                    ),
+                    (
+                        spec(w, '*', Align::Center),
+                        format!("{:*^w$}", x, w = w),
+                        format!("{:*^w$}", y, w = w),
+                    ),
+                    (
+                        spec(w, 'é', Align::Right),
+                        format!("{:é>w$}", x, w = w),
+                        format!("{:é>w$}", y, w = w),
+                    ),
+                ];
+                for &(spec, ref xs, ref ys) in &cases {
+                    assert_eq!(Spec(&x, &spec).to_string(), *xs);
+                    let expected = xs.cmp(ys);
+                    assert_eq!(cmp_with_spec(&x, &y, spec), expected, "{:?}", spec);
+                    assert_eq!(
+                        cmp_with_spec(&y, &x, spec),
+                        expected.reverse(),
+                        "{:?},rev",
+                        spec
+                    );
+                }
+            }
+        }
+
+        check(42, 240);
+        check(42, 42);
+        check(-42, 42);
+        check(7, 1000);
+        check(0, -1);
+        check(123_456, 99);
+    }
+
+    #[test]
+    fn precision() {
+        let spec = FmtSpec {
+            width: Some(6),
+            fill: '_',
+            align: Align::Right,
+            precision: Some(2),
+        };
+        assert_eq!(Spec(&1.0, &spec).to_string(), format!("{:_>6.2}", 1.0));
+        assert_eq!(Spec("abcd", &spec).to_string(), format!("{:_>6.2}", "abcd"));
+        // `"__1.00" < "__1.01"`
+        assert_eq!(cmp_with_spec(&1.0, &1.009, spec), Ordering::Less);
+        // `"____ab" == "____ab"`
+        assert_eq!(cmp_with_spec("abc", "abd", spec), Ordering::Equal);
+    }
+}
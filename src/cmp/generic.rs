@@ -1,40 +1,170 @@
+use std::cell::Cell;
 use std::cmp::Ordering;
+use std::convert::TryInto;
 use std::fmt::{self, Display, Write};
 use std::hash::Hasher;
+use std::mem;
 
 pub fn eq<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> bool {
-    cmp(lhs, rhs) == Ordering::Equal
+    // `diverge` formats `rhs` once for each chunk of `lhs` and only notices a length mismatch when
+    // it reaches the end of the shorter one, which is quadratic for a long `lhs` written in many
+    // chunks. So we first run `diverge` on the first chunk of `lhs` only, which decides the result
+    // with a single formatting of each value if `lhs` is written in a single chunk. Otherwise, we
+    // compare the lengths before running `diverge` on the whole `lhs`, which costs one extra
+    // formatting of each value.
+    let first = FirstChunk {
+        value: lhs,
+        truncated: Cell::new(false),
+    };
+    let ret = diverge(&first, rhs).ret;
+    if !first.truncated.get() {
+        return ret == Ordering::Equal;
+    }
+    if measure(lhs) != measure(rhs) {
+        return false;
+    }
+    diverge(lhs, rhs).ret == Ordering::Equal
+}
+
+/// A `Display` adapter that stops formatting the value at its second non-empty chunk.
+struct FirstChunk<'a, T: ?Sized> {
+    value: &'a T,
+    /// Whether the value had more than one non-empty chunk.
+    truncated: Cell<bool>,
+}
+
+impl<T: Display + ?Sized> Display for FirstChunk<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Adapter<'a, 'b> {
+            f: &'a mut fmt::Formatter<'b>,
+            written: bool,
+            truncated: &'a Cell<bool>,
+        }
+
+        impl Write for Adapter<'_, '_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                if s.is_empty() {
+                    return Ok(());
+                }
+                if self.written {
+                    self.truncated.set(true);
+                    return Err(fmt::Error);
+                }
+                self.written = true;
+                self.f.write_str(s)
+            }
+        }
+
+        let mut adapter = Adapter {
+            f,
+            written: false,
+            truncated: &self.truncated,
+        };
+        write!(adapter, "{}", self.value)
+    }
+}
+
+/// Returns the length of the `Display` representation of a value.
+fn measure<T: Display + ?Sized>(value: &T) -> usize {
+    struct Counter(usize);
+
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    let _ = write!(counter, "{}", value);
+    counter.0
 }
 
 pub fn cmp<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    diverge(lhs, rhs).ret
+}
+
+/// The result of comparing two values in their `Display` representations.
+pub struct Divergence {
+    pub ret: Ordering,
+    /// Byte position of the first differing byte, or the length of the shorter representation if
+    /// it is a prefix of the other.
+    pub pos: usize,
+    /// `pos` rounded down to the nearest `char` boundary.
+    pub char_pos: usize,
+    /// Whether the whole `lhs` is a prefix of `rhs` (including the case that they are equal).
+    pub lhs_is_prefix: bool,
+    /// Whether either of the `Display` implementations returned an error on its own.
+    pub error: bool,
+}
+
+pub fn diverge<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Divergence {
     struct State {
         ret: Ordering,
         rhs_is_remaining: bool,
+        /// `Divergence::pos`, set on an early-return.
+        pos: usize,
+        /// `Divergence::char_pos`, set on an early-return.
+        char_pos: usize,
+        error: bool,
     }
 
     struct Rhs<'a, T: ?Sized> {
         rhs: &'a T,
         /// Byte position in `lhs.to_string()` that we are reading.
         pos: usize,
+        /// Whether `lhs` has written any chunk, including an empty one.
+        lhs_is_written: bool,
         state: State,
     }
 
     let state = State {
         ret: Ordering::Equal,
         rhs_is_remaining: false,
+        pos: 0,
+        char_pos: 0,
+        error: false,
+    };
+    let mut adapter = Rhs {
+        rhs,
+        pos: 0,
+        lhs_is_written: false,
+        state,
     };
-    let mut adapter = Rhs { rhs, pos: 0, state };
 
     // `write!` returns an error if: 1. the adapter is trying an early-return, or 2. `T::fmt`
-    // returned an error. 2. indicates an incorrect `Display` implementation so we only need to
-    // consider the case of 1.
-    let _ = write!(&mut adapter, "{}", &lhs);
+    // returned an error. The adapter always sets `state.ret` or `state.error` before trying
+    // an early-return, so we can tell 2. from 1. by checking them.
+    let result = write!(&mut adapter, "{}", &lhs);
+    let lhs_error = result.is_err() && adapter.state.ret == Ordering::Equal && !adapter.state.error;
+    if !adapter.lhs_is_written && !lhs_error {
+        // `rhs` has never been formatted, so we need to check if `rhs` is empty.
+        let _ = adapter.write_str("");
+    }
+    let error = lhs_error || adapter.state.error;
+
+    if adapter.state.ret != Ordering::Equal {
+        return Divergence {
+            ret: adapter.state.ret,
+            pos: adapter.state.pos,
+            char_pos: adapter.state.char_pos,
+            lhs_is_prefix: false,
+            error,
+        };
+    }
 
-    return adapter.state.ret.then(if adapter.state.rhs_is_remaining {
-        Ordering::Less
-    } else {
-        Ordering::Equal
-    });
+    // The whole `lhs` is a prefix of `rhs`.
+    return Divergence {
+        ret: if adapter.state.rhs_is_remaining {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        },
+        pos: adapter.pos,
+        char_pos: adapter.pos,
+        lhs_is_prefix: true,
+        error,
+    };
 
     struct Lhs<'a> {
         lhs: &'a [u8],
@@ -50,6 +180,7 @@ pub fn cmp<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Orderi
             //       ^^^^^^^^^-lhs
             // U |-+---+---+-------+--|
 
+            self.lhs_is_written = true;
             self.state.rhs_is_remaining = false;
 
             let mut adapter = Lhs {
@@ -58,21 +189,34 @@ pub fn cmp<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Orderi
                 state: &mut self.state,
             };
 
-            let _ = write!(&mut adapter, "{}", self.rhs);
+            let result = write!(&mut adapter, "{}", self.rhs);
 
-            // Get `is_empty` first to make borrowck happy.
-            let lhs_is_empty = adapter.lhs.is_empty();
-            if self.state.ret != Ordering::Equal {
-                // Short-circuit by returning an error.
+            // Get the number of bytes read first to make borrowck happy.
+            let read = lhs.len() - adapter.lhs.len();
+            // `Lhs` always sets `state.ret` or `state.rhs_is_remaining` before trying
+            // an early-return, so the error is from `U::fmt` otherwise.
+            if result.is_err() && self.state.ret == Ordering::Equal && !self.state.rhs_is_remaining
+            {
+                self.state.error = true;
                 return Err(fmt::Error);
             }
-            if !lhs_is_empty {
+            if self.state.ret == Ordering::Equal && read < lhs.len() {
                 // `adapter.lhs` remained after `rhs` was exhausted, which means that `lhs` is
                 // longer than `rhs`.
                 // T |---+-------+--|
                 //       ^-pos ^^^-adapter.lhs
                 // U |-+---+---|
                 self.state.ret = Ordering::Greater;
+            }
+            if self.state.ret != Ordering::Equal {
+                self.state.pos = self.pos + read;
+                // `lhs` starts at a `char` boundary, so this never goes past the start of `lhs`.
+                let mut char_read = read;
+                while !lhs.is_char_boundary(char_read) {
+                    char_read -= 1;
+                }
+                self.state.char_pos = self.pos + char_read;
+                // Short-circuit by returning an error.
                 return Err(fmt::Error);
             }
 
@@ -96,8 +240,11 @@ pub fn cmp<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Orderi
             let rhs = &rhs.as_bytes()[skip..];
 
             let read = rhs.len().min(self.lhs.len());
-            self.state.ret = self.lhs[0..read].cmp(&rhs[0..read]);
-            if self.state.ret != Ordering::Equal {
+            if let Some(i) = mismatch(&self.lhs[0..read], &rhs[0..read]) {
+                self.state.ret = self.lhs[i].cmp(&rhs[i]);
+                // Leave the differing byte at the start of `self.lhs` to let `Rhs` know the
+                // position.
+                self.lhs = &self.lhs[i..];
                 return Err(fmt::Error);
             }
             self.lhs = &self.lhs[read..];
@@ -121,17 +268,393 @@ pub fn cmp<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Orderi
     }
 }
 
+/// Returns the index of the first differing byte of two slices of the same length.
+pub fn mismatch(lhs: &[u8], rhs: &[u8]) -> Option<usize> {
+    const WORD: usize = mem::size_of::<usize>();
+
+    // Compare `usize`-sized chunks first.
+    let mut offset = 0;
+    for (l, r) in lhs.chunks_exact(WORD).zip(rhs.chunks_exact(WORD)) {
+        // Reading the chunks as little-endian puts the first byte in the least significant
+        // position, so the number of trailing zeros of the XOR tells the first differing byte.
+        let l = usize::from_le_bytes(l.try_into().unwrap());
+        let r = usize::from_le_bytes(r.try_into().unwrap());
+        let diff = l ^ r;
+        if diff != 0 {
+            return Some(offset + diff.trailing_zeros() as usize / 8);
+        }
+        offset += WORD;
+    }
+
+    lhs[offset..]
+        .iter()
+        .zip(&rhs[offset..])
+        .position(|(l, r)| l != r)
+        .map(|i| offset + i)
+}
+
 pub fn hash<T: Display + ?Sized, H: Hasher>(hashee: &T, hasher: &mut H) {
-    struct Adapter<'a, H>(&'a mut H);
-    impl<H: Hasher> Write for Adapter<'_, H> {
-        fn write_str(&mut self, s: &str) -> fmt::Result {
-            self.0.write(s.as_bytes());
-            Ok(())
+    let mut adapter = HashWriter::new(hasher);
+    write!(adapter, "{}", &hashee).unwrap();
+    adapter.finish();
+}
+
+pub fn hash_with_terminator<T: Display + ?Sized, H: Hasher>(
+    hashee: &T,
+    hasher: &mut H,
+    terminator: u8,
+) {
+    let mut adapter = HashWriter::with_terminator(hasher, terminator);
+    write!(adapter, "{}", &hashee).unwrap();
+    adapter.finish();
+}
+
+/// Size of the blocks to feed the hasher with.
+const BLOCK: usize = 64;
+
+/// A `fmt::Write` adapter that feeds the written bytes to a `Hasher` in a chunk-invariant way.
+///
+/// `Hasher` implementations are not required to produce the same result for different splits of
+/// the same byte sequence (e.g. `write(b"ab")` vs. `write(b"a"); write(b"b")`), so we cannot feed
+/// the chunks from `Display::fmt` to the hasher as they are. Instead, we re-split the byte sequence
+/// into blocks of `BLOCK` bytes (and the final partial block), which only depend on the byte
+/// sequence.
+pub struct HashWriter<'a, H> {
+    hasher: &'a mut H,
+    buf: [u8; BLOCK],
+    len: usize,
+    terminator: u8,
+}
+
+impl<'a, H: Hasher> HashWriter<'a, H> {
+    pub fn new(hasher: &'a mut H) -> Self {
+        HashWriter::with_terminator(hasher, 0xff)
+    }
+
+    /// The `terminator` must be a byte that never appears in UTF-8.
+    pub fn with_terminator(hasher: &'a mut H, terminator: u8) -> Self {
+        HashWriter {
+            hasher,
+            buf: [0; BLOCK],
+            len: 0,
+            terminator,
         }
     }
 
-    write!(Adapter(&mut *hasher), "{}", &hashee).unwrap();
-    // Pass an extra `0xFF` to avoid prefix collisions.
-    // cf. <https://doc.rust-lang.org/1.57.0/core/hash/trait.Hash.html#prefix-collisions>
-    hasher.write_u8(0xff);
+    /// Feeds the final partial block and the terminator to the hasher.
+    pub fn finish(self) {
+        if self.len > 0 {
+            self.hasher.write(&self.buf[..self.len]);
+        }
+        // Pass an extra terminator (`0xFF` by default) to avoid prefix collisions. Since the
+        // terminator never appears in UTF-8, this makes the whole byte sequence fed to the hasher
+        // prefix-free.
+        // cf. <https://doc.rust-lang.org/1.57.0/core/hash/trait.Hash.html#prefix-collisions>
+        self.hasher.write_u8(self.terminator);
+    }
+}
+
+impl<H: Hasher> Write for HashWriter<'_, H> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut s = s.as_bytes();
+        // Fast path for the small chunks that fit in the pending partial block, which is common
+        // for `Display` implementations that write a `char` or a few at a time.
+        if s.len() < BLOCK - self.len {
+            self.buf[self.len..self.len + s.len()].copy_from_slice(s);
+            self.len += s.len();
+            return Ok(());
+        }
+
+        if self.len > 0 {
+            // Fill the pending partial block first.
+            let n = s.len().min(BLOCK - self.len);
+            self.buf[self.len..self.len + n].copy_from_slice(&s[..n]);
+            self.len += n;
+            s = &s[n..];
+            if self.len < BLOCK {
+                return Ok(());
+            }
+            self.hasher.write(&self.buf);
+            self.len = 0;
+        }
+
+        let mut blocks = s.chunks_exact(BLOCK);
+        for block in &mut blocks {
+            self.hasher.write(block);
+        }
+        let rest = blocks.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.len = rest.len();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Formatter;
+
+    use super::*;
+    use crate::testing::Chars;
+
+    #[test]
+    fn eq_matches_str_eq() {
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let expected = x == y;
+            assert_eq!(eq(x, y), expected);
+            assert_eq!(eq(&Chars(x), y), expected, "chunked lhs");
+            assert_eq!(eq(x, &Chars(y)), expected, "chunked rhs");
+            assert_eq!(eq(&Chars(x), &Chars(y)), expected, "chunked");
+        }
+
+        for &(x, y) in &[
+            ("", ""),
+            ("", "a"),
+            ("abc", "abc"),
+            ("abc", "abd"),
+            ("abc", "abcd"),
+            ("abcd", "abc"),
+            ("abc", "xbc"),
+            ("abcdefghijklmnop", "abcdefghijklmnoq"),
+            ("abcdefghijklmnop", "abcdefghijklmnopq"),
+        ] {
+            check(x, y);
+            check(y, x);
+        }
+    }
+
+    #[test]
+    fn measure_matches_len() {
+        assert_eq!(measure(""), 0);
+        assert_eq!(measure("abc"), 3);
+        assert_eq!(measure(&Chars("abc")), 3);
+        assert_eq!(measure(&Chars("\u{e9}")), 2);
+        assert_eq!(measure(&Chars("")), 0);
+    }
+
+    #[test]
+    fn eq_formats_single_chunk_once() {
+        use std::cell::Cell;
+
+        /// Counts the calls to `Display::fmt`.
+        struct Counted<'a, T>(T, &'a Cell<usize>);
+
+        impl<T: Display> Display for Counted<'_, T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                self.1.set(self.1.get() + 1);
+                self.0.fmt(f)
+            }
+        }
+
+        #[track_caller]
+        fn check<T: Display, U: Display>(x: T, y: U, expected: bool, counts: (usize, usize)) {
+            let (l, r) = (Cell::new(0), Cell::new(0));
+            assert_eq!(eq(&Counted(&x, &l), &Counted(&y, &r)), expected);
+            assert_eq!((l.get(), r.get()), counts);
+        }
+
+        check(42, 42, true, (1, 1));
+        check(42, 240, false, (1, 1));
+        check("abc", "abc", true, (1, 1));
+        check("abc", "abcd", false, (1, 1));
+        check("", "", true, (1, 1));
+        check("", "a", false, (1, 1));
+        // `lhs` is formatted up to its second chunk, then both are measured, and then they are
+        // compared if the lengths match, formatting `rhs` once for each chunk (including the empty
+        // ones) of `lhs` up to the differing one.
+        check(Chars("abc"), "abd", false, (3, 1 + 1 + 5));
+        check(Chars("abc"), "abcd", false, (2, 2));
+    }
+
+    #[test]
+    fn short_circuits_on_first_chunk() {
+        use std::cell::Cell;
+
+        /// Like `Chars`, but counts the non-empty chunks it has written.
+        struct Counted<'a>(&'a str, &'a Cell<usize>);
+
+        impl Display for Counted<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let mut buf = [0; 4];
+                self.0.chars().try_for_each(|c| {
+                    self.1.set(self.1.get() + 1);
+                    f.write_str(c.encode_utf8(&mut buf))?;
+                    f.write_str("")
+                })
+            }
+        }
+
+        let (l, r) = (Cell::new(0), Cell::new(0));
+        let (lhs, rhs) = (Counted("abc", &l), Counted("xbc", &r));
+        let (lhs, rhs): (&dyn Display, &dyn Display) = (&lhs, &rhs);
+        assert_eq!(cmp(lhs, rhs), Ordering::Less);
+        assert_eq!((l.get(), r.get()), (1, 1));
+
+        // `eq` only measures the lengths beforehand if `lhs` is written in multiple chunks.
+        r.set(0);
+        assert!(!eq("abc", rhs));
+        assert_eq!(r.get(), 1);
+    }
+
+    #[test]
+    fn nested_display() {
+        use crate::Cmp;
+
+        /// Formats itself as `depth` levels of parentheses around the leaf, recursing through
+        /// `Cmp`'s `Display` implementation.
+        struct Nested(usize, &'static str);
+
+        impl Display for Nested {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                match self.0 {
+                    0 => f.write_str(self.1),
+                    n => write!(f, "({})", Cmp(Nested(n - 1, self.1))),
+                }
+            }
+        }
+
+        // The adapters are not nested along with the `Display` implementations, so the stack
+        // usage is proportional to the sum of the recursion depths of the two values.
+        let depth = 500;
+        let (x, y) = (Nested(depth, "a"), Nested(depth, "b"));
+        assert_eq!(cmp(&x, &y), Ordering::Less);
+        assert_eq!(cmp(&y, &x), Ordering::Greater);
+        assert_eq!(cmp(&x, &x), Ordering::Equal);
+        assert!(eq(&x, &Nested(depth, "a")));
+        assert!(!eq(&x, &Nested(depth - 1, "a")));
+        assert_eq!(cmp(&Nested(depth - 1, "a"), &x), Ordering::Greater);
+    }
+
+    #[test]
+    fn write_method_agnostic() {
+        /// Writes the string with `write_char` only.
+        struct WriteChar<'a>(&'a str);
+
+        impl Display for WriteChar<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                self.0.chars().try_for_each(|c| f.write_char(c))
+            }
+        }
+
+        /// Writes the string by splitting it in halves recursively with nested `write!`s.
+        struct WriteFmt<'a>(&'a str);
+
+        impl Display for WriteFmt<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let s = self.0;
+                let mut mid = s.len() / 2;
+                while !s.is_char_boundary(mid) {
+                    mid -= 1;
+                }
+                if mid == 0 {
+                    return f.write_str(s);
+                }
+                write!(f, "{}{}", WriteFmt(&s[..mid]), WriteFmt(&s[mid..]))
+            }
+        }
+
+        fn fnv<T: Display + ?Sized>(value: &T) -> u64 {
+            let mut hasher = crate::testing::Recorder::default();
+            hash(value, &mut hasher);
+            // `Recorder::finish` hashes the bytes regardless of how they are split.
+            hasher.finish()
+        }
+
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let expected = diverge(x, y);
+            let (xs, ys) = (
+                [&x as &dyn Display, &Chars(x), &WriteChar(x), &WriteFmt(x)],
+                [&y as &dyn Display, &Chars(y), &WriteChar(y), &WriteFmt(y)],
+            );
+            for (i, &x) in xs.iter().enumerate() {
+                for (j, &y) in ys.iter().enumerate() {
+                    let actual = diverge(x, y);
+                    assert_eq!(actual.ret, expected.ret, "{},{}", i, j);
+                    assert_eq!(actual.pos, expected.pos, "{},{},pos", i, j);
+                    assert_eq!(actual.char_pos, expected.char_pos, "{},{},char_pos", i, j);
+                    assert_eq!(
+                        actual.lhs_is_prefix, expected.lhs_is_prefix,
+                        "{},{},prefix",
+                        i, j
+                    );
+                    assert_eq!(eq(x, y), expected.ret == Ordering::Equal, "{},{},eq", i, j);
+                }
+                assert_eq!(fnv(x), fnv(xs[0]), "{},hash", i);
+            }
+        }
+
+        for &(x, y) in &[
+            ("", ""),
+            ("", "a"),
+            ("abc", "abc"),
+            ("abc", "abd"),
+            ("abc", "abcd"),
+            ("Ünicode", "Ünicöde"),
+            ("\u{10000}\u{10001}", "\u{10000}\u{10002}"),
+            ("abcdefghijklmnop", "abcdefghijklmnoq"),
+            ("abcdefghijklmnop", "abcdefghijklmnopq"),
+        ] {
+            check(x, y);
+            check(y, x);
+        }
+    }
+
+    #[test]
+    fn mismatch_matches_naive() {
+        let base: [u8; 40] = [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39,
+        ];
+        for len in 0..=base.len() {
+            let lhs = &base[..len];
+            assert_eq!(mismatch(lhs, lhs), None, "{}", len);
+            for i in 0..len {
+                let mut rhs = base;
+                rhs[i] ^= 0x80;
+                assert_eq!(mismatch(lhs, &rhs[..len]), Some(i), "{},{}", len, i);
+                // Differing bytes after the first mismatch should not matter.
+                rhs[len - 1] ^= 0x01;
+                assert_eq!(mismatch(lhs, &rhs[..len]), Some(i), "{},{},tail", len, i);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_matches_naive_sip() {
+        use std::collections::hash_map::DefaultHasher;
+
+        /// Feeds each chunk to the hasher as it is.
+        struct Naive<'a, H>(&'a mut H);
+
+        impl<H: Hasher> Write for Naive<'_, H> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.write(s.as_bytes());
+                Ok(())
+            }
+        }
+
+        #[track_caller]
+        fn check(s: &str) {
+            let mut expected = DefaultHasher::new();
+            write!(Naive(&mut expected), "{}", Chars(s)).unwrap();
+            expected.write_u8(0xff);
+            let expected = expected.finish();
+
+            for value in [&s as &dyn Display, &Chars(s)] {
+                let mut hasher = DefaultHasher::new();
+                hash(value, &mut hasher);
+                assert_eq!(hasher.finish(), expected, "{:?}", s);
+            }
+        }
+
+        check("");
+        check("a");
+        check("Ünicode");
+        check(&"0123456789abcdef".repeat(4));
+        check(&"0123456789abcdef".repeat(4)[1..]);
+        check(&"Ünicode ".repeat(20));
+    }
 }
@@ -0,0 +1,163 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+
+use super::{cmp, eq};
+
+/// Compares two values in their `Display` representations, skipping the bytes in `ignore`.
+///
+/// This yields the same result as comparing the `to_string()` outputs with every byte contained in
+/// `ignore` removed, without heap allocation. This is useful for comparing numbers with thousands
+/// separators, for example.
+///
+/// ## Panics
+///
+/// Panics if `ignore` contains a non-ASCII byte, since removing it would break the UTF-8
+/// encoding of the representations.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::cmp_ignoring;
+///
+/// assert!(cmp_ignoring("1,000,000", &1000000, b",").is_eq());
+/// // `"1000" < "999"`
+/// assert!(cmp_ignoring("1,000", "999", b",").is_lt());
+/// ```
+#[must_use]
+pub fn cmp_ignoring<T: Display + ?Sized, U: Display + ?Sized>(
+    lhs: &T,
+    rhs: &U,
+    ignore: &[u8],
+) -> Ordering {
+    check_ascii(ignore);
+    cmp(&Ignoring(lhs, ignore), &Ignoring(rhs, ignore))
+}
+
+/// Tests two values for equality in their `Display` representations, skipping the bytes in
+/// `ignore`.
+///
+/// See [`cmp_ignoring`] for the details.
+///
+/// ## Panics
+///
+/// Panics if `ignore` contains a non-ASCII byte.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::eq_ignoring;
+///
+/// assert!(eq_ignoring("1.5", "15", b"."));
+/// assert!(!eq_ignoring("1.5", "15", b""));
+/// ```
+#[must_use]
+pub fn eq_ignoring<T: Display + ?Sized, U: Display + ?Sized>(
+    lhs: &T,
+    rhs: &U,
+    ignore: &[u8],
+) -> bool {
+    check_ascii(ignore);
+    eq(&Ignoring(lhs, ignore), &Ignoring(rhs, ignore))
+}
+
+fn check_ascii(ignore: &[u8]) {
+    if !ignore.is_ascii() {
+        panic!("`ignore` must only contain ASCII bytes");
+    }
+}
+
+/// A `Display` adapter that removes the bytes in the second field from the representation.
+struct Ignoring<'a, T: ?Sized>(&'a T, &'a [u8]);
+
+impl<T: Display + ?Sized> Display for Ignoring<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        struct Adapter<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            ignore: &'a [u8],
+        }
+
+        impl Write for Adapter<'_, '_> {
+            fn write_str(&mut self, mut s: &str) -> fmt::Result {
+                // The ignored bytes are ASCII, so the remaining pieces are valid `str`s.
+                while let Some(i) = s.bytes().position(|b| self.ignore.contains(&b)) {
+                    self.f.write_str(&s[..i])?;
+                    s = &s[i + 1..];
+                }
+                self.f.write_str(s)
+            }
+        }
+
+        write!(Adapter { f, ignore: self.1 }, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::{String, ToString};
+
+    use super::*;
+    use crate::testing::Chars;
+
+    fn remove(s: &str, ignore: &[u8]) -> String {
+        s.chars()
+            .filter(|&c| !c.is_ascii() || !ignore.contains(&(c as u8)))
+            .collect()
+    }
+
+    #[test]
+    fn matches_removed_str_cmp() {
+        #[track_caller]
+        fn check(x: &str, y: &str, ignore: &[u8]) {
+            let (rx, ry) = (remove(x, ignore), remove(y, ignore));
+            let expected = rx.cmp(&ry);
+            assert_eq!(cmp_ignoring(x, y, ignore), expected);
+            assert_eq!(cmp_ignoring(y, x, ignore), expected.reverse(), "rev");
+            assert_eq!(
+                cmp_ignoring(&Chars(x), &Chars(y), ignore),
+                expected,
+                "chars"
+            );
+            assert_eq!(cmp_ignoring(&Chars(x), y, ignore), expected, "chars,str");
+            assert_eq!(cmp_ignoring(x, &Chars(y), ignore), expected, "str,chars");
+            assert_eq!(eq_ignoring(x, y, ignore), rx == ry);
+            assert_eq!(eq_ignoring(&Chars(x), y, ignore), rx == ry, "chars");
+            assert_eq!(Ignoring(x, ignore).to_string(), rx);
+            assert_eq!(Ignoring(&Chars(x), ignore).to_string(), rx, "chars");
+        }
+
+        check("1,000,000", "1000000", b",");
+        check("1,000", "999", b",");
+        check("1,0,0,0", "1,000", b",");
+        check("1.5", "15", b"");
+        check("1.5", "15", b".");
+        check("1.5", "1,5", b".,");
+        check(",,,", "", b",");
+        check(",a", "a,", b",");
+        check("a,b", "a-b", b",");
+        check("a,b", "a-b", b",-");
+        check("é,é", "éé", b",");
+        check("", "", b"");
+    }
+
+    #[test]
+    fn numbers() {
+        assert!(eq_ignoring(&format_args!("{},{:03}", 1, 0), &1000, b","));
+        assert_eq!(cmp_ignoring(&1.5, &15, b""), Ordering::Less);
+        assert!(eq_ignoring(&1.5, &15, b"."));
+        assert!(!eq_ignoring(&-1.5, &15, b"."));
+        assert!(eq_ignoring(&-1.5, &15, b".-"));
+    }
+
+    #[test]
+    #[should_panic(expected = "`ignore` must only contain ASCII bytes")]
+    fn non_ascii() {
+        let _ = cmp_ignoring("é", "", &[0xc3]);
+    }
+}
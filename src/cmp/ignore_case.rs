@@ -0,0 +1,198 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
+use std::str;
+
+use super::{cmp, eq, hash};
+
+/// A wrapper type that compares the inner value in its `Display` representation, ignoring ASCII
+/// case.
+///
+/// This implements [`Eq`][std::cmp::Eq], [`Ord`][std::cmp::Ord] and [`Hash`][std::hash::Hash]
+/// traits with [`eq_ignore_case`], [`cmp_ignore_case`] and a case-insensitive variant of
+/// [`hash`](super::hash()) function.
+///
+/// Only ASCII letters are folded (to lowercase) and the other bytes including non-ASCII characters
+/// are compared verbatim, like [`str::eq_ignore_ascii_case`].
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::CmpCaseInsensitive;
+///
+/// assert_eq!(CmpCaseInsensitive("Foo"), CmpCaseInsensitive("foo"));
+/// assert!(CmpCaseInsensitive("Bar") < CmpCaseInsensitive("foo"));
+/// assert_ne!(CmpCaseInsensitive("Ä"), CmpCaseInsensitive("ä"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct CmpCaseInsensitive<T: ?Sized = dyn Display>(pub T);
+
+impl<T: Display + ?Sized> Display for CmpCaseInsensitive<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Display + ?Sized, U: Display + ?Sized> PartialEq<CmpCaseInsensitive<U>>
+    for CmpCaseInsensitive<T>
+{
+    fn eq(&self, other: &CmpCaseInsensitive<U>) -> bool {
+        eq_ignore_case(&self.0, &other.0)
+    }
+}
+
+impl<T: Display + ?Sized> Eq for CmpCaseInsensitive<T> {}
+
+impl<T: Display + ?Sized, U: Display + ?Sized> PartialOrd<CmpCaseInsensitive<U>>
+    for CmpCaseInsensitive<T>
+{
+    fn partial_cmp(&self, other: &CmpCaseInsensitive<U>) -> Option<Ordering> {
+        Some(cmp_ignore_case(&self.0, &other.0))
+    }
+}
+
+impl<T: Display + ?Sized> Ord for CmpCaseInsensitive<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_ignore_case(&self.0, &other.0)
+    }
+}
+
+impl<T: Display + ?Sized> Hash for CmpCaseInsensitive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash(&AsciiLowercase(&self.0), state)
+    }
+}
+
+/// Tests two values for equality in their `Display` representations, ignoring ASCII case.
+///
+/// This yields the same result as
+/// `lhs.to_string().eq_ignore_ascii_case(&rhs.to_string())` without heap allocation.
+///
+/// ## Note
+///
+/// This has the same caveats as [`eq`](super::eq()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp::eq_ignore_case("Ferris", "FERRIS"));
+/// ```
+#[must_use]
+pub fn eq_ignore_case<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> bool {
+    eq(&AsciiLowercase(lhs), &AsciiLowercase(rhs))
+}
+
+/// Compares two values in their `Display` representations, ignoring ASCII case.
+///
+/// This yields the same result as
+/// `lhs.to_string().to_ascii_lowercase().cmp(&rhs.to_string().to_ascii_lowercase())` without heap
+/// allocation.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// // `"bar" < "foo"` although `"foo" < "Bar"`.
+/// assert!(fmt_cmp::cmp::cmp_ignore_case("Bar", "foo").is_lt());
+/// ```
+#[must_use]
+pub fn cmp_ignore_case<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    cmp(&AsciiLowercase(lhs), &AsciiLowercase(rhs))
+}
+
+/// A `Display` adapter that converts ASCII uppercase letters to lowercase.
+struct AsciiLowercase<'a, T: ?Sized>(&'a T);
+
+impl<T: Display + ?Sized> Display for AsciiLowercase<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        struct Adapter<'a, 'b>(&'a mut Formatter<'b>);
+
+        impl Write for Adapter<'_, '_> {
+            fn write_str(&mut self, mut s: &str) -> fmt::Result {
+                if !s.bytes().any(|b| b.is_ascii_uppercase()) {
+                    return self.0.write_str(s);
+                }
+
+                let mut buf = [0_u8; 64];
+                while !s.is_empty() {
+                    let mut n = s.len().min(buf.len());
+                    while !s.is_char_boundary(n) {
+                        n -= 1;
+                    }
+                    let (chunk, rest) = s.split_at(n);
+                    let buf = &mut buf[..n];
+                    buf.copy_from_slice(chunk.as_bytes());
+                    buf.make_ascii_lowercase();
+                    // Safety: `buf` is a copy of a `str` split at `char` boundaries, and converting
+                    // ASCII bytes to lowercase keeps it valid UTF-8.
+                    self.0.write_str(unsafe { str::from_utf8_unchecked(buf) })?;
+                    s = rest;
+                }
+
+                Ok(())
+            }
+        }
+
+        write!(Adapter(f), "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::testing::hash_of;
+
+    #[test]
+    fn matches_str_cmp() {
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let expected = x.to_ascii_lowercase().cmp(&y.to_ascii_lowercase());
+            assert_eq!(cmp_ignore_case(x, y), expected);
+            assert_eq!(cmp_ignore_case(y, x), expected.reverse(), "rev");
+            assert_eq!(eq_ignore_case(x, y), x.eq_ignore_ascii_case(y));
+            assert_eq!(AsciiLowercase(x).to_string(), x.to_ascii_lowercase());
+        }
+
+        check("", "");
+        check("ABC", "abc");
+        check("aBc", "AbC");
+        check("Bar", "foo");
+        check("Foo", "bar");
+        check("[", "a");
+        check("[", "A");
+        check("Ä", "ä");
+        check("Äb", "ÄB");
+        check("ÄB", "äb");
+
+        // Longer than the internal buffer.
+        let long = "Ünicode ÄND ASCII ".repeat(10);
+        check(&long, &long.to_ascii_lowercase());
+        check(&long, &long.to_ascii_uppercase());
+        check(&long, &long["Ü".len()..]);
+    }
+
+    #[test]
+    fn hash_matches_eq() {
+        assert_eq!(
+            hash_of(&CmpCaseInsensitive("ABC")),
+            hash_of(&CmpCaseInsensitive("abc"))
+        );
+        assert_eq!(
+            hash_of(&CmpCaseInsensitive("Ünicode")),
+            hash_of(&CmpCaseInsensitive("ÜNICODE"))
+        );
+        assert_ne!(
+            hash_of(&CmpCaseInsensitive("Ünicode")),
+            hash_of(&CmpCaseInsensitive("ünicode"))
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use alloc::string::String;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
+
+use super::{FmtEq, FmtOrd};
+
+/// A wrapper type that caches the `Display` representation of the inner value and compares the
+/// value by the cached string.
+///
+/// Unlike [`Cmp<T>`](super::Cmp), which calls `Display::fmt` on every comparison, this calls
+/// `Display::fmt` only once at construction, trading memory for speed. This is useful when the
+/// `Display` implementation is expensive and the value is compared many times, e.g., as a key of
+/// `BTreeMap`.
+///
+/// The ordering and the equivalence relation are the same as those of `Cmp<T>`, provided that
+/// the `Display` implementation of `T` emits the same string on every call.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// use fmt_cmp::cmp::CmpKey;
+///
+/// let values: BTreeSet<CmpKey<u32>> = (1..=10).map(CmpKey::new).collect();
+/// assert!(values
+///    .into_iter()
+///    .map(|key| key.into_parts().0)
+///    .eq([1, 10, 2, 3, 4, 5, 6, 7, 8, 9]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CmpKey<T> {
+    value: T,
+    repr: String,
+}
+
+impl<T: Display> CmpKey<T> {
+    /// Wraps a value of type `T` as a `CmpKey<T>`, formatting the value with its `Display`
+    /// implementation.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the `Display` implementation returns an error.
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        let mut repr = String::new();
+        write!(repr, "{}", value).expect("a Display implementation returned an error unexpectedly");
+        CmpKey { value, repr }
+    }
+
+    /// Returns a reference to the inner value.
+    #[must_use]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the cached `Display` representation of the inner value.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.repr
+    }
+
+    /// Unwraps the inner value and the cached `Display` representation of it.
+    #[must_use]
+    pub fn into_parts(self) -> (T, String) {
+        (self.value, self.repr)
+    }
+}
+
+impl<T> Borrow<str> for CmpKey<T> {
+    fn borrow(&self) -> &str {
+        &self.repr
+    }
+}
+
+impl<T> Display for CmpKey<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.repr.fmt(f)
+    }
+}
+
+impl<T, U> PartialEq<CmpKey<U>> for CmpKey<T> {
+    fn eq(&self, other: &CmpKey<U>) -> bool {
+        self.repr == other.repr
+    }
+}
+
+impl<T> Eq for CmpKey<T> {}
+
+impl<T, U> PartialOrd<CmpKey<U>> for CmpKey<T> {
+    fn partial_cmp(&self, other: &CmpKey<U>) -> Option<Ordering> {
+        Some(self.repr.cmp(&other.repr))
+    }
+}
+
+impl<T> Ord for CmpKey<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.repr.cmp(&other.repr)
+    }
+}
+
+// Hashes in the same way as `str` to satisfy `Borrow<str>` trait's contract.
+impl<T> Hash for CmpKey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.repr.hash(state)
+    }
+}
+
+impl<T> FmtEq for CmpKey<T> {}
+impl<T> FmtOrd for CmpKey<T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn formats_once() {
+        struct Counter<'a>(&'a Cell<u32>, u32);
+
+        impl Display for Counter<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                self.0.set(self.0.get() + 1);
+                self.1.fmt(f)
+            }
+        }
+
+        let count = Cell::new(0);
+        let keys = [
+            CmpKey::new(Counter(&count, 42)),
+            CmpKey::new(Counter(&count, 240)),
+            CmpKey::new(Counter(&count, 3)),
+        ];
+        assert_eq!(count.get(), 3);
+
+        for x in &keys {
+            for y in &keys {
+                assert_eq!(x.cmp(y), super::super::cmp(x.value(), y.value()));
+            }
+        }
+        // Each of the above `super::super::cmp` calls formats the values by itself.
+        let expected = count.get();
+
+        for x in &keys {
+            for y in &keys {
+                let _ = x.cmp(y);
+                let _ = x == y;
+            }
+        }
+        assert_eq!(count.get(), expected);
+
+        let (value, repr) = CmpKey::new(Counter(&count, 42)).into_parts();
+        assert_eq!(value.1, 42);
+        assert_eq!(repr, "42");
+    }
+}
@@ -0,0 +1,268 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use super::{eq, generic};
+
+/// A hash map keyed by the `Display` representations of the keys.
+///
+/// This is a thin wrapper around `HashMap<K, V>` that hashes and compares the keys like
+/// [`Cmp<K>`](super::Cmp) does. Unlike `HashMap<Cmp<K>, V>`, the map can be queried with a value
+/// of any `Display` type through [`get_by_display`](DisplayMap::get_by_display), which finds
+/// the entry whose key has the same `Display` representation as the query.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::DisplayMap;
+///
+/// let mut map = DisplayMap::new();
+/// map.insert(42, "foo");
+/// map.insert(0x2A0, "bar");
+///
+/// assert_eq!(map.get(&42), Some(&"foo"));
+/// assert_eq!(map.get_by_display("42"), Some(&"foo"));
+/// assert_eq!(map.get_by_display(&format_args!("{}{}", 67, 2)), Some(&"bar"));
+/// assert_eq!(map.get_by_display(&format_args!("{:x}", 0x2A0)), None);
+/// ```
+pub struct DisplayMap<K, V> {
+    map: HashMap<Key<K>, V>,
+}
+
+/// A key of `DisplayMap`, which hashes and compares the inner value in its `Display`
+/// representation.
+///
+/// This always uses the streaming implementation of [`hash`](super::hash()) so that the hash
+/// values agree with those of the queries regardless of the types.
+struct Key<K>(K);
+
+/// A `Display` value to look up a `DisplayMap` with.
+trait Query {
+    fn as_display(&self) -> &dyn Display;
+}
+
+/// A reference to a possibly unsized query, which `&dyn Query` can be made from.
+struct QueryRef<'a, Q: ?Sized>(&'a Q);
+
+impl<K: Display, V> DisplayMap<K, V> {
+    /// Creates an empty `DisplayMap`.
+    #[must_use]
+    pub fn new() -> Self {
+        DisplayMap {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Creates an empty `DisplayMap` with at least the specified capacity.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        DisplayMap {
+            map: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map already has a key with the same `Display` representation, the value is updated
+    /// and the old value is returned. The key is not updated in that case, like `HashMap::insert`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.map.insert(Key(key), value)
+    }
+
+    /// Returns a reference to the value corresponding to the key with the same `Display`
+    /// representation as `key`.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_by_display(key)
+    }
+
+    /// Returns a reference to the value corresponding to the key with the same `Display`
+    /// representation as `query`.
+    #[must_use]
+    pub fn get_by_display<Q: Display + ?Sized>(&self, query: &Q) -> Option<&V> {
+        self.map.get(&QueryRef(query) as &dyn Query)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key with the same `Display`
+    /// representation as `query`.
+    #[must_use]
+    pub fn get_mut_by_display<Q: Display + ?Sized>(&mut self, query: &Q) -> Option<&mut V> {
+        self.map.get_mut(&QueryRef(query) as &dyn Query)
+    }
+
+    /// Removes the entry whose key has the same `Display` representation as `query` from the map,
+    /// returning the key and the value.
+    pub fn remove_by_display<Q: Display + ?Sized>(&mut self, query: &Q) -> Option<(K, V)> {
+        self.map
+            .remove_entry(&QueryRef(query) as &dyn Query)
+            .map(|(Key(k), v)| (k, v))
+    }
+
+    /// Returns an iterator over the entries of the map in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.map.iter().map(|(Key(k), v)| (k, v))
+    }
+}
+
+impl<K: Display, V> Default for DisplayMap<K, V> {
+    fn default() -> Self {
+        DisplayMap::new()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for DisplayMap<K, V> {
+    fn clone(&self) -> Self {
+        DisplayMap {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for DisplayMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.map.iter().map(|(Key(k), v)| (k, v)))
+            .finish()
+    }
+}
+
+impl<K: Display, V> Extend<(K, V)> for DisplayMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|(k, v)| (Key(k), v)));
+    }
+}
+
+impl<K: Display, V> std::iter::FromIterator<(K, V)> for DisplayMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = DisplayMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Clone> Clone for Key<K> {
+    fn clone(&self) -> Self {
+        Key(self.0.clone())
+    }
+}
+
+impl<K: Display> PartialEq for Key<K> {
+    fn eq(&self, other: &Self) -> bool {
+        eq(&self.0, &other.0)
+    }
+}
+
+impl<K: Display> Eq for Key<K> {}
+
+impl<K: Display> Hash for Key<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        generic::hash(&self.0, state)
+    }
+}
+
+impl<K: Display> Query for Key<K> {
+    fn as_display(&self) -> &dyn Display {
+        &self.0
+    }
+}
+
+impl<Q: Display + ?Sized> Query for QueryRef<'_, Q> {
+    fn as_display(&self) -> &dyn Display {
+        // `&Q` is `Sized` even if `Q` isn't.
+        &self.0
+    }
+}
+
+impl<'a, K: Display + 'a> Borrow<dyn Query + 'a> for Key<K> {
+    fn borrow(&self) -> &(dyn Query + 'a) {
+        self
+    }
+}
+
+impl PartialEq for dyn Query + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        eq(self.as_display(), other.as_display())
+    }
+}
+
+impl Eq for dyn Query + '_ {}
+
+impl Hash for dyn Query + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        generic::hash(self.as_display(), state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cmp;
+
+    #[test]
+    fn get_by_display() {
+        let mut map = DisplayMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert(Cmp("foo".to_string()), 1), None);
+        assert_eq!(map.insert(Cmp("42".to_string()), 2), None);
+        assert_eq!(map.insert(Cmp("".to_string()), 3), None);
+        assert_eq!(map.insert(Cmp("foo".to_string()), 4), Some(1));
+        assert_eq!(map.len(), 3);
+
+        assert_eq!(map.get(&Cmp("foo".to_string())), Some(&4));
+        assert_eq!(map.get_by_display("foo"), Some(&4));
+        assert_eq!(map.get_by_display(&"foo"), Some(&4));
+        assert_eq!(map.get_by_display(&format_args!("f{}", "oo")), Some(&4));
+        assert_eq!(map.get_by_display(&42), Some(&2));
+        assert_eq!(map.get_by_display(&format_args!("{:X}", 0x42)), Some(&2));
+        assert_eq!(map.get_by_display(""), Some(&3));
+        assert_eq!(map.get_by_display("fo"), None);
+        assert_eq!(map.get_by_display(&format_args!("{:x}", 42)), None);
+
+        *map.get_mut_by_display(&format_args!("{}", 42)).unwrap() += 10;
+        assert_eq!(map.get_by_display("42"), Some(&12));
+        assert_eq!(
+            map.remove_by_display(&42),
+            Some((Cmp("42".to_string()), 12))
+        );
+        assert_eq!(map.get_by_display("42"), None);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn long_keys() {
+        // Longer than the block size of `generic::hash`.
+        let key = "0123456789abcdef".repeat(10);
+
+        /// Writes the string in chunks of 3 bytes.
+        struct Chunked<'a>(&'a str);
+
+        impl Display for Chunked<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                self.0
+                    .as_bytes()
+                    .chunks(3)
+                    .try_for_each(|chunk| f.write_str(std::str::from_utf8(chunk).unwrap()))
+            }
+        }
+
+        let map: DisplayMap<_, _> = vec![(key.clone(), 1), (key[1..].to_string(), 2)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.get_by_display(&Chunked(&key)), Some(&1));
+        assert_eq!(map.get_by_display(&Chunked(&key[1..])), Some(&2));
+        assert_eq!(map.get_by_display(&Chunked(&key[2..])), None);
+    }
+}
@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use super::cmp;
+
+/// Returns a reference to the greater of two values in their `Display` representations.
+///
+/// Returns `a` if the representations are equal. Note that this differs from `std::cmp::max`,
+/// which returns the second argument in that case.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::max_by_display;
+///
+/// // `"42" > "240"`
+/// assert_eq!(max_by_display(&42, &240), &42);
+/// assert_eq!(max_by_display("foo", "bar"), "foo");
+/// ```
+#[must_use]
+pub fn max_by_display<'a, T: Display + ?Sized>(a: &'a T, b: &'a T) -> &'a T {
+    max(a, b)
+}
+
+/// Returns a reference to the lesser of two values in their `Display` representations.
+///
+/// Returns `a` if the representations are equal.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::min_by_display;
+///
+/// // `"240" < "42"`
+/// assert_eq!(min_by_display(&42, &240), &240);
+/// ```
+#[must_use]
+pub fn min_by_display<'a, T: Display + ?Sized>(a: &'a T, b: &'a T) -> &'a T {
+    min(a, b)
+}
+
+/// Returns the greater of two values in their `Display` representations.
+///
+/// This is the by-value variant of [`max_by_display`], and returns `a` if the representations are
+/// equal.
+///
+/// ## Example
+///
+/// ```
+/// assert_eq!(fmt_cmp::cmp::max(42, 240), 42);
+/// // `"0" > "-0"`
+/// assert_eq!(fmt_cmp::cmp::max(-0.0, 0.0).to_string(), "0");
+/// ```
+#[must_use]
+pub fn max<T: Display>(a: T, b: T) -> T {
+    if cmp(&a, &b) == Ordering::Less {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns the lesser of two values in their `Display` representations.
+///
+/// This is the by-value variant of [`min_by_display`], and returns `a` if the representations are
+/// equal.
+///
+/// ## Example
+///
+/// ```
+/// assert_eq!(fmt_cmp::cmp::min(42, 240), 240);
+/// ```
+#[must_use]
+pub fn min<T: Display>(a: T, b: T) -> T {
+    if cmp(&a, &b) == Ordering::Greater {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Key;
+
+    #[test]
+    fn lexicographic() {
+        assert_eq!(max_by_display(&42, &240), &42);
+        assert_eq!(max_by_display(&240, &42), &42);
+        assert_eq!(min_by_display(&42, &240), &240);
+        assert_eq!(min_by_display(&240, &42), &240);
+        assert_eq!(max_by_display("foo", "foobar"), "foobar");
+        assert_eq!(min_by_display("foo", "foobar"), "foo");
+        assert_eq!(max(9, 10), 9);
+        assert_eq!(min(9, 10), 10);
+    }
+
+    #[test]
+    fn ties() {
+        let (a, b) = (Key(1, 0), Key(1, 1));
+        assert_eq!(max_by_display(&a, &b), &a);
+        assert_eq!(min_by_display(&a, &b), &a);
+        assert_eq!(max_by_display(&b, &a), &b);
+        assert_eq!(min_by_display(&b, &a), &b);
+        assert_eq!(max(Key(1, 0), Key(1, 1)), Key(1, 0));
+        assert_eq!(min(Key(1, 0), Key(1, 1)), Key(1, 0));
+    }
+}
@@ -1,13 +1,75 @@
 //! Stringy comparison utility.
 
+mod bytes;
+mod collapse;
+#[cfg(feature = "unicode")]
+mod collate;
+#[cfg(feature = "cmp-display")]
+mod display;
+mod fmt_spec;
 mod generic;
+mod ignore;
+mod ignore_case;
+#[cfg(feature = "alloc")]
+mod key;
+#[cfg(feature = "std")]
+mod map;
+mod minmax;
+mod natural;
+mod option;
+mod prefix;
+mod seq;
+mod sort;
 #[cfg(fmt_cmp_semver_exempt)]
 mod spec;
+#[cfg(feature = "alloc")]
+mod stream;
+#[cfg(feature = "trace")]
+mod trace;
+mod trim;
+
+pub use self::bytes::{cmp_bytes, eq_bytes, CmpBytes};
+pub use self::collapse::{cmp_ws_collapsed, eq_ws_collapsed};
+#[cfg(feature = "unicode")]
+pub use self::collate::cmp_collate;
+#[cfg(feature = "cmp-display")]
+pub use self::display::CmpDisplay;
+pub use self::fmt_spec::{cmp_with_spec, Align, FmtSpec};
+pub use self::ignore::{cmp_ignoring, eq_ignoring};
+pub use self::ignore_case::{cmp_ignore_case, eq_ignore_case, CmpCaseInsensitive};
+#[cfg(feature = "alloc")]
+pub use self::key::CmpKey;
+#[cfg(feature = "std")]
+pub use self::map::DisplayMap;
+pub use self::minmax::{max, max_by_display, min, min_by_display};
+pub use self::natural::cmp_natural;
+pub use self::option::CmpOption;
+pub use self::prefix::cmp_prefix;
+pub use self::seq::{cmp_joined, cmp_tuple2, cmp_tuple3, eq_joined, CmpSeq};
+pub use self::sort::{by_display, sort_slice};
+#[cfg(feature = "alloc")]
+pub use self::sort::{by_display_key, insert_sorted_by_display, sorted};
+#[cfg(feature = "indexmap")]
+pub use self::sort::{sort_index_map_keys_by_display, sort_index_set_by_display};
+#[cfg(feature = "alloc")]
+pub use self::stream::StreamCmp;
+#[cfg(feature = "trace")]
+pub use self::trace::{cmp_traced, CmpStats};
+pub use self::trim::{cmp_trimmed, eq_trimmed, CmpTrimmed};
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use std::borrow::Borrow;
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::mem;
+use std::ops::{self, Bound, Deref, DerefMut, RangeBounds};
+use std::str::FromStr;
 
 use super::{FmtEq, FmtOrd};
 
@@ -16,11 +78,55 @@ use self::generic as imp;
 #[cfg(fmt_cmp_semver_exempt)]
 use self::spec as imp;
 
+#[cfg(all(feature = "debug-checks", debug_assertions))]
+use self::checked_cmp as cmp_imp;
+#[cfg(not(all(feature = "debug-checks", debug_assertions)))]
+use self::imp::cmp as cmp_imp;
+
 /// A wrapper type that compares the inner value in its `Display` representation.
 ///
 /// This implements [`Eq`][std::cmp::Eq], [`Ord`][std::cmp::Ord] and [`Hash`][std::hash::Hash]
 /// traits with [`eq`], [`cmp`] and [`hash`] functions.
 ///
+/// While the inner value is accessible through the public field, [`Cmp::new`] and
+/// [`Cmp::into_inner`] are the preferred ways to wrap and unwrap a value.
+///
+/// The ordering is the total order of the `Display` representations as strings, even if `T`
+/// itself is only partially ordered (like `f64`), as long as the `Display` implementation is
+/// deterministic. So `Cmp<T>` can be used with `slice::sort_unstable`, `BTreeMap` and the like
+/// regardless of `T`'s own comparison traits.
+///
+/// The `Display` implementation of `Cmp<T>` forwards the formatting flags like `{:#}` and
+/// `{:>8}` to `T`'s, but the comparison always uses the representation formatted with `{}`, i.e.,
+/// without any flags. So if `T` formats differently in the alternate form, `Cmp<T>` may compare
+/// differently from what `format!("{:#}", cmp)` shows. To compare in the alternate form, wrap the
+/// value in `format_args!("{:#}", value)` and pass it to [`cmp`] instead:
+///
+/// ```
+/// use std::fmt::{self, Display, Formatter};
+///
+/// /// Displays `-1` as `-` in the alternate form.
+/// struct Sign(i32);
+///
+/// impl Display for Sign {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         match self.0 {
+///             -1 if f.alternate() => f.write_str("-"),
+///             n => n.fmt(f),
+///         }
+///     }
+/// }
+///
+/// let (x, y) = (Sign(-1), Sign(-10));
+/// assert_eq!(format!("{:#}", fmt_cmp::Cmp(&x)), "-");
+/// // `"-1" < "-10"`
+/// assert!(fmt_cmp::Cmp(&x) < fmt_cmp::Cmp(&y));
+/// // `"-" < "-10"`, which happens to agree in this case.
+/// assert!(fmt_cmp::cmp(&format_args!("{:#}", x), &format_args!("{:#}", y)).is_lt());
+/// // `"-" < "-1"`
+/// assert!(fmt_cmp::cmp(&format_args!("{:#}", x), &y).is_lt());
+/// ```
+///
 /// ## Example
 ///
 /// Wrapping `!FmtOrd` types:
@@ -30,6 +136,14 @@ use self::spec as imp;
 /// assert!(fmt_cmp::Cmp(42) > fmt_cmp::Cmp(240));
 /// ```
 ///
+/// Comparing with strings:
+///
+/// ```
+/// assert_eq!(fmt_cmp::Cmp(42), *"42");
+/// assert_eq!("42", fmt_cmp::Cmp(42));
+/// assert!(fmt_cmp::Cmp(42) > "240");
+/// ```
+///
 /// Sorting integers _lexicographically_:
 ///
 #[cfg_attr(feature = "alloc", doc = " ```")]
@@ -38,17 +152,273 @@ use self::spec as imp;
 /// #
 /// use std::collections::BTreeSet;
 ///
-/// let mut values: BTreeSet<fmt_cmp::Cmp<u32>> = (1..=10).map(fmt_cmp::Cmp).collect();
+/// let mut values: BTreeSet<fmt_cmp::Cmp<u32>> = (1..=10).map(fmt_cmp::Cmp::new).collect();
 /// assert!(values
 ///    .into_iter()
-///    .map(|cmp| cmp.0)
+///    .map(fmt_cmp::Cmp::into_inner)
 ///    .eq([1, 10, 2, 3, 4, 5, 6, 7, 8, 9]));
 /// ```
+///
+/// A max-heap of boxed trait objects, ordered by their `Display` representations:
+///
+#[cfg_attr(feature = "alloc", doc = " ```")]
+#[cfg_attr(not(feature = "alloc"), doc = " ```ignore")]
+/// # extern crate alloc as std;
+/// #
+/// use std::boxed::Box;
+/// use std::collections::BinaryHeap;
+/// use std::fmt::Display;
+///
+/// let mut heap: BinaryHeap<Box<fmt_cmp::Cmp>> = BinaryHeap::new();
+/// heap.push(fmt_cmp::Cmp::from_boxed(Box::new(42) as Box<dyn Display>));
+/// heap.push(fmt_cmp::Cmp::from_boxed(Box::new("Hello") as Box<dyn Display>));
+/// heap.push(fmt_cmp::Cmp::from_boxed(Box::new('!') as Box<dyn Display>));
+/// assert_eq!(heap.pop().unwrap().to_string(), "Hello");
+/// assert_eq!(heap.pop().unwrap().to_string(), "42");
+/// assert_eq!(heap.pop().unwrap().to_string(), "!");
+/// ```
+///
+/// Values of different types can also be kept in a single sorted set, since `Box<Cmp<T>>`
+/// coerces to `Box<Cmp<dyn Display>>` (the default type parameter) just like `Box<T>` coerces to
+/// `Box<dyn Display>`:
+///
+#[cfg_attr(feature = "alloc", doc = " ```")]
+#[cfg_attr(not(feature = "alloc"), doc = " ```ignore")]
+/// # extern crate alloc as std;
+/// #
+/// use std::boxed::Box;
+/// use std::collections::BTreeSet;
+/// use std::string::{String, ToString};
+/// use std::vec::Vec;
+///
+/// let mut set: BTreeSet<Box<fmt_cmp::Cmp>> = BTreeSet::new();
+/// set.insert(Box::new(fmt_cmp::Cmp(42_u32)));
+/// set.insert(Box::new(fmt_cmp::Cmp(String::from("abc"))));
+/// set.insert(Box::new(fmt_cmp::Cmp(3.5_f64)));
+/// // Equal to the integer in its `Display` representation.
+/// assert!(!set.insert(Box::new(fmt_cmp::Cmp("42"))));
+/// let values: Vec<_> = set.iter().map(|v| v.to_string()).collect();
+/// assert_eq!(values, ["3.5", "42", "abc"]);
+/// ```
 #[derive(Clone, Copy, Debug)]
 #[repr(transparent)]
 pub struct Cmp<T: ?Sized = dyn Display>(pub T);
 
 impl<T: Display + ?Sized> Cmp<T> {
+    /// Wraps a value of type `T` as a `Cmp<T>`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// assert!(fmt_cmp::Cmp::new(42) > fmt_cmp::Cmp::new(240));
+    /// ```
+    #[must_use]
+    pub fn new(value: T) -> Self
+    where
+        T: Sized,
+    {
+        Cmp(value)
+    }
+
+    /// Wraps a value of type `T` as a `Cmp<T>`, checking the [`FmtEq`] contract of `T` in debug
+    /// builds.
+    ///
+    /// With `debug_assertions` enabled, this compares `value` with itself and with a clone of it
+    /// both with `==` and in their `Display` representations, and panics if the results disagree.
+    /// This is only a cheap sanity check that catches, e.g., a `Display` implementation that isn't
+    /// deterministic or a `PartialEq` implementation that isn't reflexive. See the `test_util`
+    /// module (with `test-util` feature) for a more thorough check.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the check fails in debug builds.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// assert!(fmt_cmp::Cmp::checked("42") > fmt_cmp::Cmp::checked("240"));
+    /// ```
+    #[must_use]
+    pub fn checked(value: T) -> Self
+    where
+        T: FmtEq + Clone,
+    {
+        #[cfg(debug_assertions)]
+        {
+            let clone = value.clone();
+            // Comparing `value` with itself formats it twice, which catches a non-deterministic
+            // `Display` implementation.
+            #[allow(clippy::eq_op)]
+            let holds = (value == value) == generic::eq(&value, &value)
+                && (value == clone) == generic::eq(&value, &clone);
+            assert!(holds, "`FmtEq` contract violated by `{}`", value);
+        }
+        Cmp(value)
+    }
+
+    /// Unwraps the inner value of a `Cmp<T>`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// assert_eq!(fmt_cmp::Cmp::new(42).into_inner(), 42);
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> T
+    where
+        T: Sized,
+    {
+        self.0
+    }
+
+    /// Maps a `Cmp<T>` to `Cmp<U>` by applying a function to the inner value.
+    ///
+    /// Note that the resulting `Cmp<U>` compares by the `Display` representation of `U`, so the
+    /// ordering of the mapped values may differ from that of the original values.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use fmt_cmp::Cmp;
+    ///
+    /// let cmp = Cmp::new("Ferris").map(str::to_uppercase).map(|s| s + "!");
+    /// assert_eq!(cmp, Cmp("FERRIS!"));
+    ///
+    /// // `"42" > "240"` but `"102" < "300"`.
+    /// assert!(Cmp::new(42) > Cmp::new(240));
+    /// assert!(Cmp::new(42).map(|n| n + 60) < Cmp::new(240).map(|n| n + 60));
+    /// ```
+    #[must_use]
+    pub fn map<U: Display, F: FnOnce(T) -> U>(self, f: F) -> Cmp<U>
+    where
+        T: Sized,
+    {
+        Cmp(f(self.0))
+    }
+
+    /// Converts a `Cmp<U>` to `Cmp<T>` by converting the inner value with `TryFrom`.
+    ///
+    /// This is what `impl<T: TryFrom<U>, U> TryFrom<Cmp<U>> for Cmp<T>` would do, which cannot be
+    /// implemented because it would overlap with the blanket `TryFrom` implementation of the
+    /// standard library for `T == U`.
+    ///
+    /// Note that the resulting `Cmp<T>` compares by the `Display` representation of `T`, like
+    /// [`map`](Cmp::map).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use fmt_cmp::Cmp;
+    ///
+    /// assert_eq!(Cmp::<u8>::try_from_cmp(Cmp(255_u16)), Ok(Cmp(255_u8)));
+    /// assert!(Cmp::<u8>::try_from_cmp(Cmp(300_u16)).is_err());
+    /// ```
+    pub fn try_from_cmp<U>(value: Cmp<U>) -> Result<Self, T::Error>
+    where
+        T: Sized + TryFrom<U>,
+    {
+        T::try_from(value.0).map(Cmp)
+    }
+
+    /// Returns the greater of two values in their `Display` representations.
+    ///
+    /// This is the same as [`Ord::max`], i.e., returns `other` if the representations are equal,
+    /// and is provided as an inherent method for convenience.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use fmt_cmp::Cmp;
+    ///
+    /// // `"42" > "240"`
+    /// assert_eq!(Cmp(42).max(Cmp(240)), Cmp(42));
+    /// ```
+    #[must_use]
+    pub fn max(self, other: Self) -> Self
+    where
+        T: Sized,
+    {
+        Ord::max(self, other)
+    }
+
+    /// Returns the lesser of two values in their `Display` representations.
+    ///
+    /// This is the same as [`Ord::min`], i.e., returns `self` if the representations are equal,
+    /// and is provided as an inherent method for convenience.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use fmt_cmp::Cmp;
+    ///
+    /// // `"240" < "42"`
+    /// assert_eq!(Cmp(42).min(Cmp(240)), Cmp(240));
+    /// ```
+    #[must_use]
+    pub fn min(self, other: Self) -> Self
+    where
+        T: Sized,
+    {
+        Ord::min(self, other)
+    }
+
+    /// Restricts a value to an interval in the `Display` representations.
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is less than `min`.
+    /// Otherwise, returns `self`. This is the same as `Ord::clamp`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use fmt_cmp::Cmp;
+    ///
+    /// // `"2" < "25" < "3"`
+    /// assert_eq!(Cmp(25).clamp(Cmp(2), Cmp(3)), Cmp(25));
+    /// assert_eq!(Cmp(100).clamp(Cmp(2), Cmp(3)), Cmp(2));
+    /// ```
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self
+    where
+        T: Sized,
+    {
+        assert!(min <= max);
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Restricts a value to an interval in the `Display` representations, taking the bounds as
+    /// bare values.
+    ///
+    /// This is the same as `self.clamp(Cmp(min), Cmp(max))`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `min > max` in the `Display` representations.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use fmt_cmp::Cmp;
+    ///
+    /// assert_eq!(Cmp(4).clamp_display(10, 3), Cmp(3));
+    /// ```
+    #[must_use]
+    pub fn clamp_display(self, min: T, max: T) -> Self
+    where
+        T: Sized,
+    {
+        self.clamp(Cmp(min), Cmp(max))
+    }
+
     /// Wraps a reference of type `T` as a reference of `Cmp<T>`.
     #[must_use]
     pub fn from_ref(value: &T) -> &Self {
@@ -62,6 +432,29 @@ impl<T: Display + ?Sized> Cmp<T> {
         inner(value)
     }
 
+    /// Converts a `&Cmp<T>` into `&Cmp<dyn Display>`, erasing the type of the inner value.
+    ///
+    /// This is the same as the unsizing coercion `self as &Cmp<dyn Display>`, but is handy in
+    /// method chains and where the target type cannot be inferred.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use fmt_cmp::Cmp;
+    ///
+    /// let (x, y, z) = (Cmp(42_u8), Cmp("foo"), Cmp(3.25_f64));
+    /// let mut values = vec![x.as_dyn(), y.as_dyn(), z.as_dyn()];
+    /// values.sort();
+    /// assert!(values.iter().map(ToString::to_string).eq(["3.25", "42", "foo"]));
+    /// ```
+    #[must_use]
+    pub fn as_dyn(&self) -> &Cmp<dyn Display + '_>
+    where
+        T: Sized,
+    {
+        self
+    }
+
     /// Converts a `Box<T>` into `Box<Cmp<T>>`.
     #[cfg(feature = "alloc")]
     #[must_use]
@@ -84,6 +477,98 @@ impl<T: Display + ?Sized> Cmp<T> {
         unsafe { alloc::boxed::Box::<T>::from_raw(leaked) }
     }
 
+    /// Converts an `Rc<T>` into `Rc<Cmp<T>>`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn from_rc(rc: alloc::rc::Rc<T>) -> alloc::rc::Rc<Self> {
+        let raw = alloc::rc::Rc::into_raw(rc) as *const Cmp<T>;
+        // Safety:
+        // - The `#[repr(transparent)]` attribute ensures that `Cmp<T>` has the same layout as `T`,
+        //   so the reference counts are placed at the same offset from the data pointer.
+        // - `raw` is a data pointer returned by `Rc::into_raw`.
+        unsafe { alloc::rc::Rc::from_raw(raw) }
+    }
+
+    /// Converts an `Rc<Cmp<T>>` into an `Rc<T>`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn into_rc_inner(self: alloc::rc::Rc<Self>) -> alloc::rc::Rc<T> {
+        let raw = alloc::rc::Rc::into_raw(self) as *const T;
+        // Safety:
+        // - The `#[repr(transparent)]` attribute ensures that `Cmp<T>` has the same layout as `T`,
+        //   so the reference counts are placed at the same offset from the data pointer.
+        // - `raw` is a data pointer returned by `Rc::into_raw`.
+        unsafe { alloc::rc::Rc::from_raw(raw) }
+    }
+
+    /// Converts an `Arc<T>` into `Arc<Cmp<T>>`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn from_arc(arc: alloc::sync::Arc<T>) -> alloc::sync::Arc<Self> {
+        let raw = alloc::sync::Arc::into_raw(arc) as *const Cmp<T>;
+        // Safety:
+        // - The `#[repr(transparent)]` attribute ensures that `Cmp<T>` has the same layout as `T`,
+        //   so the reference counts are placed at the same offset from the data pointer.
+        // - `raw` is a data pointer returned by `Arc::into_raw`.
+        unsafe { alloc::sync::Arc::from_raw(raw) }
+    }
+
+    /// Converts an `Arc<Cmp<T>>` into an `Arc<T>`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn into_arc_inner(self: alloc::sync::Arc<Self>) -> alloc::sync::Arc<T> {
+        let raw = alloc::sync::Arc::into_raw(self) as *const T;
+        // Safety:
+        // - The `#[repr(transparent)]` attribute ensures that `Cmp<T>` has the same layout as `T`,
+        //   so the reference counts are placed at the same offset from the data pointer.
+        // - `raw` is a data pointer returned by `Arc::into_raw`.
+        unsafe { alloc::sync::Arc::from_raw(raw) }
+    }
+
+    /// Returns a value whose `Debug` representation shows the `Display` representation of the
+    /// inner value, quoted and escaped like a string.
+    ///
+    /// This is useful for logging, since the `Debug` implementation of `Cmp<T>` itself shows the
+    /// `Debug` representation of the inner value, which may look different from the `Display`
+    /// representation that `Cmp<T>` compares.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let value = fmt_cmp::Cmp(format_args!("{}\t{}", "foo", 42));
+    /// assert_eq!(format!("{:?}", value.display_debug()), r#"Cmp("foo\t42")"#);
+    /// ```
+    pub fn display_debug(&self) -> impl Debug + '_ {
+        struct DisplayDebug<'a, T: ?Sized>(&'a T);
+
+        impl<T: Display + ?Sized> Debug for DisplayDebug<'_, T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                use std::fmt::Write;
+
+                struct Escape<'a, 'b>(&'a mut Formatter<'b>);
+
+                impl Write for Escape<'_, '_> {
+                    fn write_str(&mut self, s: &str) -> fmt::Result {
+                        // Escape like `<str as Debug>::fmt`, which leaves single quotes as-is.
+                        s.chars().try_for_each(|c| {
+                            if c == '\'' {
+                                self.0.write_char(c)
+                            } else {
+                                c.escape_debug().try_for_each(|c| self.0.write_char(c))
+                            }
+                        })
+                    }
+                }
+
+                f.write_str("Cmp(\"")?;
+                write!(Escape(f), "{}", self.0)?;
+                f.write_str("\")")
+            }
+        }
+
+        DisplayDebug(&self.0)
+    }
+
     #[cfg(feature = "alloc")]
     fn from_mut(value: &mut T) -> &mut Self {
         fn inner<'a, T: ?Sized>(value: &'a mut T) -> &'a mut Cmp<T> {
@@ -97,18 +582,84 @@ impl<T: Display + ?Sized> Cmp<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Cmp<alloc::string::String> {
+    /// Renders `fmt::Arguments` into a `String` and wraps it as a `Cmp<String>`.
+    ///
+    /// This is a convenience for storing the output of `format_args!` (which borrows its inputs)
+    /// as an owned comparison key. Unlike most of the items of this crate, this allocates memory
+    /// for the rendered string, so pass `&format_args!(..)` to [`cmp`] or [`eq`] directly if you
+    /// only need to compare it once.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use fmt_cmp::Cmp;
+    ///
+    /// assert_eq!(Cmp::from_fmt(format_args!("{:X}", 42)), Cmp("2A".to_string()));
+    /// ```
+    #[must_use]
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        Cmp(alloc::fmt::format(args))
+    }
+}
+
 impl<T> AsRef<T> for Cmp<T> {
     fn as_ref(&self) -> &T {
         &self.0
     }
 }
 
+/// ## Example
+///
+/// ```
+/// let hello = fmt_cmp::Cmp("hello");
+/// assert_eq!(hello.len(), 5);
+/// assert_eq!(format!("{}", hello), "hello");
+/// ```
+impl<T: Display + ?Sized> Deref for Cmp<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Display + ?Sized> DerefMut for Cmp<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
 impl<T: Default + Display> Default for Cmp<T> {
     fn default() -> Self {
         Cmp(T::default())
     }
 }
 
+/// Parses a value of `T` and wraps it in `Cmp`.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::Cmp;
+///
+/// let c: Cmp<u32> = "42".parse().unwrap();
+/// assert_eq!(c, Cmp(42));
+/// assert!("-1".parse::<Cmp<u32>>().is_err());
+///
+/// // `String::from_str` is infallible.
+/// let c: Cmp<String> = "foo".parse().unwrap();
+/// assert_eq!(c, "foo");
+/// ```
+impl<T: FromStr + Display> FromStr for Cmp<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, T::Err> {
+        s.parse().map(Cmp)
+    }
+}
+
 // `AsRef<Cmp<T>> for T` cannot be implemented due to conflict with
 // `AsRef<U> for &T where T: AsRef<U>`.
 impl<'a, T: Display + ?Sized> From<&'a T> for &'a Cmp<T> {
@@ -124,6 +675,79 @@ impl<T: Display + ?Sized> From<alloc::boxed::Box<T>> for alloc::boxed::Box<Cmp<T
     }
 }
 
+// `Box<Cmp<T>>` is already `Clone` for `T: Clone` through `Clone for Box<T>`, but `Cmp<str>` is not
+// `Clone` since it is unsized.
+/// Clones the string into a new allocation.
+///
+/// `Box<Cmp<dyn Display>>` is not `Clone` since `dyn Display` provides no means to clone the
+/// value.
+#[cfg(feature = "alloc")]
+impl Clone for alloc::boxed::Box<Cmp<str>> {
+    fn clone(&self) -> Self {
+        Cmp::from_boxed(alloc::boxed::Box::<str>::from(&self.0))
+    }
+}
+
+// `Borrow<Cmp<U>> for Cmp<T> where T: Borrow<U>` cannot be implemented due to conflict with
+// `Borrow<T> for T`.
+/// ## Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use fmt_cmp::Cmp;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(Cmp(String::from("abc")), 42);
+/// map.insert(Cmp(String::from("xyz")), 240);
+/// assert_eq!(map.get(Cmp::from_ref("abc")), Some(&42));
+///
+/// // The type annotation is needed because `Cmp<String>` also implements `Borrow<Cmp<String>>`.
+/// let range = map.range::<Cmp<str>, _>(Cmp::from_ref("a")..Cmp::from_ref("m"));
+/// assert!(range.map(|(_, &v)| v).eq([42]));
+/// ```
+#[cfg(feature = "alloc")]
+impl Borrow<Cmp<str>> for Cmp<alloc::string::String> {
+    fn borrow(&self) -> &Cmp<str> {
+        Cmp::from_ref(&*self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Borrow<Cmp<str>> for Cmp<alloc::boxed::Box<str>> {
+    fn borrow(&self) -> &Cmp<str> {
+        Cmp::from_ref(&*self.0)
+    }
+}
+
+/// Implements `RangeBounds<Cmp<str>>` for the range types over `&Cmp<str>`, which the standard
+/// library only implements for `Sized` types. This makes, e.g.,
+/// `BTreeMap<Cmp<String>, V>::range::<Cmp<str>, _>` accept
+/// `Cmp::from_ref("a")..Cmp::from_ref("m")`.
+macro_rules! range_bounds {
+    ($($ty:ident => |$s:pat| $start:expr, |$e:pat| $end:expr;)*) => {$(
+        impl<'a> RangeBounds<Cmp<str>> for ops::$ty<&'a Cmp<str>> {
+            fn start_bound(&self) -> Bound<&Cmp<str>> {
+                let $s = self;
+                $start
+            }
+
+            fn end_bound(&self) -> Bound<&Cmp<str>> {
+                let $e = self;
+                $end
+            }
+        }
+    )*};
+}
+
+range_bounds! {
+    Range => |r| Bound::Included(r.start), |r| Bound::Excluded(r.end);
+    RangeInclusive => |r| Bound::Included(*r.start()), |r| Bound::Included(*r.end());
+    RangeFrom => |r| Bound::Included(r.start), |_| Bound::Unbounded;
+    RangeTo => |_| Bound::Unbounded, |r| Bound::Excluded(r.end);
+    RangeToInclusive => |_| Bound::Unbounded, |r| Bound::Included(r.end);
+}
+
 impl<T: Display + ?Sized> Display for Cmp<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -163,177 +787,1803 @@ impl<T: Display + ?Sized> Hash for Cmp<T> {
 impl<T: Display + ?Sized> FmtEq for Cmp<T> {}
 impl<T: Display + ?Sized> FmtOrd for Cmp<T> {}
 
-/// Tests two values for equality in their `Display` representations.
-///
-/// This yields the same result as `lhs.to_string() == rhs.to_string()` without heap allocation.
-///
-/// ## Note
-///
-/// This may call `Display::fmt` multiple times and if it emits different strings between the calls,
-/// the resulting value is unspecified.
-///
-/// Also, the `Display` implementations may not return error as described by the documentation of
-/// [`std::fmt`]. Doing so would result in an unspecified return value or might even cause
-/// a panic in a future version.
-///
-/// ## Examples
-///
-/// Comparing floating-point numbers:
-///
-/// ```
-/// assert!(fmt_cmp::eq(&f64::NAN, &f64::NAN)); // `"NaN" == "NaN"`
-/// assert!(!fmt_cmp::eq(&0.0, &-0.0)); // `"0" != "-0"`
-/// ```
-///
-/// Comparing values of different types:
-///
-/// ```
-/// assert!(fmt_cmp::eq(&format_args!("{:X}", 0x2A), "2A"));
-/// ```
-#[must_use]
-pub fn eq<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> bool {
-    imp::eq(lhs, rhs)
+// Unlike the general `PartialEq<U>`, comparison with string types is consistent with
+// `PartialEq<Cmp<U>>` because the `Display` representation of a string is the string itself.
+macro_rules! str_cmp {
+    ($($(#[$attr:meta])* $({$($lt:tt)*})? $ty:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($($lt)*,)? T: Display + ?Sized> PartialEq<$ty> for Cmp<T> {
+            fn eq(&self, other: &$ty) -> bool {
+                eq(&self.0, other)
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($($lt)*,)? T: Display + ?Sized> PartialEq<Cmp<T>> for $ty {
+            fn eq(&self, other: &Cmp<T>) -> bool {
+                eq(self, &other.0)
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($($lt)*,)? T: Display + ?Sized> PartialOrd<$ty> for Cmp<T> {
+            fn partial_cmp(&self, other: &$ty) -> Option<Ordering> {
+                Some(cmp(&self.0, other))
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($($lt)*,)? T: Display + ?Sized> PartialOrd<Cmp<T>> for $ty {
+            fn partial_cmp(&self, other: &Cmp<T>) -> Option<Ordering> {
+                Some(cmp(self, &other.0))
+            }
+        }
+    )*};
 }
 
-/// Compares two values in their `Display` representations.
-///
-/// This yields the same result as `lhs.to_string().cmp(&rhs.to_string())` without heap allocation.
-///
-/// ## Note
+str_cmp! {
+    str;
+    {'a} &'a str;
+    #[cfg(feature = "alloc")]
+    alloc::string::String;
+}
+
+/// Serializes the inner value transparently, i.e., `Cmp(value)` is serialized in the same way as
+/// `value`.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + ?Sized> serde::Serialize for Cmp<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserializes the inner value transparently, i.e., `Cmp<T>` is deserialized in the same way as
+/// `T`.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + Display> serde::Deserialize<'de> for Cmp<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Cmp)
+    }
+}
+
+/// A wrapper type that compares the inner value in its `Display` representation in the reverse
+/// order.
 ///
-/// This may call `Display::fmt` multiple times and if it emits different strings between the calls,
-/// the resulting `Ordering` value is unspecified.
+/// This is like [`Reverse<Cmp<T>>`](std::cmp::Reverse), but works with unsized types like trait
+/// objects as well.
 ///
-/// Also, the `Display` implementations may not return error as described by the documentation of
-/// [`std::fmt`]. Doing so would result in an unspecified `Ordering` value or might even cause
-/// a panic in a future version.
+/// The equivalence relation and the hash values are the same as those of [`Cmp<T>`].
 ///
-/// ## Examples
+/// ## Example
 ///
-/// Comparing digits of integers _lexicographically_:
+/// Sorting integers _lexicographically_ in the descending order:
 ///
-/// ```
-/// assert!(fmt_cmp::cmp(&42, &240).is_gt());
-/// ```
+#[cfg_attr(feature = "alloc", doc = " ```")]
+#[cfg_attr(not(feature = "alloc"), doc = " ```ignore")]
+/// # extern crate alloc as std;
+/// #
+/// use std::collections::BTreeSet;
 ///
-/// Comparing `format_args!`:
+/// use fmt_cmp::cmp::CmpReverse;
 ///
+/// let values: BTreeSet<CmpReverse<u32>> = (1..=10).map(CmpReverse::new).collect();
+/// assert!(values
+///    .into_iter()
+///    .map(CmpReverse::into_inner)
+///    .eq([9, 8, 7, 6, 5, 4, 3, 2, 10, 1]));
 /// ```
-/// assert!(fmt_cmp::cmp(&format_args!("{:X}", 0x2A), &format_args!("{:X}", 0x9)).is_le());
-/// ```
-#[must_use]
-pub fn cmp<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
-    imp::cmp(lhs, rhs)
-}
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct CmpReverse<T: ?Sized = dyn Display>(pub T);
 
-/// Hashes a value with respect to its `Display` representation.
-///
-/// This satisfies the same property as `hashee.to_string().hash(hasher)` without heap allocation,
-/// although the exact hash values are not guaranteed to match. In particular, the following variant
-/// of [`Hash` trait's property][hash-and-eq] holds:
-///
-/// ```text
-/// format!("{}", k1) == format!("{}", k2) -> hash(k1) == hash(k2)
-/// ```
-///
-/// ## Note
-///
-/// The `Display` implementation may not return error as described by the documentation of
-/// [`std::fmt`]. Doing so would result in an unspecified hash value or might even cause
-/// a panic in a future version.
-///
-/// [hash-and-eq]: Hash#hash-and-eq
+impl<T: Display + ?Sized> CmpReverse<T> {
+    /// Wraps a value of type `T` as a `CmpReverse<T>`.
+    #[must_use]
+    pub fn new(value: T) -> Self
+    where
+        T: Sized,
+    {
+        CmpReverse(value)
+    }
+
+    /// Unwraps the inner value of a `CmpReverse<T>`.
+    #[must_use]
+    pub fn into_inner(self) -> T
+    where
+        T: Sized,
+    {
+        self.0
+    }
+
+    /// Wraps a reference of type `T` as a reference of `CmpReverse<T>`.
+    #[must_use]
+    pub fn from_ref(value: &T) -> &Self {
+        fn inner<'a, T: ?Sized>(value: &'a T) -> &'a CmpReverse<T> {
+            // Safety:
+            // - The lifetime annotations ensure that the output does not outlive the input.
+            // - The `#[repr(transparent)]` attribute ensures that `CmpReverse<T>` has the same
+            //   layout as `T`.
+            unsafe { mem::transmute::<&'a T, &'a CmpReverse<T>>(value) }
+        }
+        inner(value)
+    }
+
+    /// Converts a `Box<T>` into `Box<CmpReverse<T>>`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn from_boxed(boxed: alloc::boxed::Box<T>) -> alloc::boxed::Box<Self> {
+        let leaked: &mut CmpReverse<T> = CmpReverse::from_mut(alloc::boxed::Box::leak(boxed));
+        // Safety:
+        // - The `#[repr(transparent)]` attribute ensures that `CmpReverse<T>` has the same layout
+        //   as `T`.
+        // - `leaked` points at a block of memory currently allocated via the `Global` allocator.
+        unsafe { alloc::boxed::Box::<CmpReverse<T>>::from_raw(leaked) }
+    }
+
+    /// Converts a `Box<CmpReverse<T>>` into a `Box<T>`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn into_boxed_inner(self: alloc::boxed::Box<Self>) -> alloc::boxed::Box<T> {
+        let leaked: &mut T = &mut alloc::boxed::Box::leak(self).0;
+        // Safety:
+        // - The `#[repr(transparent)]` attribute ensures that `CmpReverse<T>` has the same layout
+        //   as `T`.
+        // - `leaked` points at a block of memory currently allocated via the `Global` allocator.
+        unsafe { alloc::boxed::Box::<T>::from_raw(leaked) }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn from_mut(value: &mut T) -> &mut Self {
+        fn inner<'a, T: ?Sized>(value: &'a mut T) -> &'a mut CmpReverse<T> {
+            // Safety:
+            // - The lifetime annotations ensure that the output does not outlive the input.
+            // - The `#[repr(transparent)]` attribute ensures that `CmpReverse<T>` has the same
+            //   layout as `T`.
+            unsafe { mem::transmute::<&'a mut T, &'a mut CmpReverse<T>>(value) }
+        }
+        inner(value)
+    }
+}
+
+impl<T> AsRef<T> for CmpReverse<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Default + Display> Default for CmpReverse<T> {
+    fn default() -> Self {
+        CmpReverse(T::default())
+    }
+}
+
+impl<'a, T: Display + ?Sized> From<&'a T> for &'a CmpReverse<T> {
+    fn from(t: &T) -> &CmpReverse<T> {
+        CmpReverse::from_ref(t)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Display + ?Sized> From<alloc::boxed::Box<T>> for alloc::boxed::Box<CmpReverse<T>> {
+    fn from(boxed: alloc::boxed::Box<T>) -> Self {
+        CmpReverse::from_boxed(boxed)
+    }
+}
+
+impl<T: Display + ?Sized> Display for CmpReverse<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Display + ?Sized, U: Display + ?Sized> PartialEq<CmpReverse<U>> for CmpReverse<T> {
+    fn eq(&self, other: &CmpReverse<U>) -> bool {
+        eq(&self.0, &other.0)
+    }
+}
+
+impl<T: Display + ?Sized> Eq for CmpReverse<T> {}
+
+impl<T: Display + ?Sized, U: Display + ?Sized> PartialOrd<CmpReverse<U>> for CmpReverse<T> {
+    fn partial_cmp(&self, other: &CmpReverse<U>) -> Option<Ordering> {
+        Some(cmp(&other.0, &self.0))
+    }
+}
+
+impl<T: Display + ?Sized> Ord for CmpReverse<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp(&other.0, &self.0)
+    }
+}
+
+impl<T: Display + ?Sized> Hash for CmpReverse<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash(&self.0, state)
+    }
+}
+
+// The ordering is reversed, so this is `!FmtOrd`.
+impl<T: Display + ?Sized> FmtEq for CmpReverse<T> {}
+
+/// Tests two values for equality in their `Display` representations.
+///
+/// This yields the same result as `lhs.to_string() == rhs.to_string()` without heap allocation.
+///
+/// ## Note
+///
+/// This may call `Display::fmt` multiple times and if it emits different strings between the calls,
+/// the resulting value is unspecified.
+///
+/// Also, the `Display` implementations may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified return value or might even cause
+/// a panic in a future version.
+///
+/// ## Examples
+///
+/// Comparing floating-point numbers:
+///
+/// ```
+/// assert!(fmt_cmp::eq(&f64::NAN, &f64::NAN)); // `"NaN" == "NaN"`
+/// assert!(!fmt_cmp::eq(&0.0, &-0.0)); // `"0" != "-0"`
+/// ```
+///
+/// Comparing values of different types:
+///
+/// ```
+/// assert!(fmt_cmp::eq(&format_args!("{:X}", 0x2A), "2A"));
+/// ```
+#[must_use]
+pub fn eq<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> bool {
+    imp::eq(lhs, rhs)
+}
+
+/// Compares two values in their `Display` representations.
+///
+/// This yields the same result as `lhs.to_string().cmp(&rhs.to_string())` without heap allocation.
+///
+/// ## Note
+///
+/// This may call `Display::fmt` multiple times and if it emits different strings between the calls,
+/// the resulting `Ordering` value is unspecified. In particular, `rhs` may be formatted once for
+/// each chunk written by `lhs`, so consider `cmp_buffered` (with `alloc` feature) if `rhs` is
+/// expensive to format.
+///
+/// `rhs` is formatted from within the `fmt::Write` implementation that `lhs` writes to, so the
+/// stack usage is bounded by the sum of the stack usages of formatting `lhs` and `rhs` (plus
+/// a constant), even if their `Display` implementations recursively format nested values. The
+/// comparison does not add any stack frames per nesting level of the values.
+///
+/// Also, the `Display` implementations may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified `Ordering` value or might even cause
+/// a panic in a future version.
+///
+/// The values are formatted with `{}`, without any flags. Pass a `format_args!` value to compare
+/// other forms, e.g., `cmp(&format_args!("{:#}", lhs), &format_args!("{:#}", rhs))`.
+///
+/// If either of the `Display` implementations panics, the panic propagates to the caller. The
+/// comparison only holds references to the values and stack-local buffers while formatting, so
+/// unwinding leaves no partially-updated state behind and the values stay usable, e.g., after
+/// catching the panic with `std::panic::catch_unwind`. The same applies to the other functions and
+/// types in this crate.
+///
+/// With `debug-checks` feature enabled, this is the same as `checked_cmp` in debug builds.
+///
+/// For primitive integers and strings, the `CmpDisplay` trait (with `cmp-display` feature) yields
+/// the same result without formatting the values.
+///
+/// ## Examples
+///
+/// Comparing digits of integers _lexicographically_:
+///
+/// ```
+/// assert!(fmt_cmp::cmp(&42, &240).is_gt());
+/// ```
+///
+/// Comparing `format_args!`:
+///
+/// ```
+/// assert!(fmt_cmp::cmp(&format_args!("{:X}", 0x2A), &format_args!("{:X}", 0x9)).is_le());
+/// ```
+#[must_use]
+pub fn cmp<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    cmp_imp(lhs, rhs)
+}
+
+/// Compares two values in their `Display` representations, checking that the `Display`
+/// implementations are deterministic.
+///
+/// This formats each value twice into `String`s and compares the strings, so this is only meant
+/// for testing `Display` implementations that are used with [`cmp`].
+///
+/// ## Panics
+///
+/// Panics if either of the values renders differently between the two calls to `Display::fmt`, or
+/// if either of the `Display` implementations returns an error.
+///
+/// ## Example
+///
+/// ```should_panic
+/// use std::cell::Cell;
+/// use std::fmt::{self, Display, Formatter};
+///
+/// /// Displays how many times it has been displayed.
+/// struct Counter(Cell<u32>);
+///
+/// impl Display for Counter {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         self.0.set(self.0.get() + 1);
+///         self.0.get().fmt(f)
+///     }
+/// }
+///
+/// let _ = fmt_cmp::cmp::checked_cmp(&Counter(Cell::new(0)), &42);
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn checked_cmp<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    fn render<T: Display + ?Sized>(value: &T, side: &str) -> String {
+        let first = value.to_string();
+        let second = value.to_string();
+        assert!(
+            first == second,
+            "the `Display` implementation of the {} operand is not deterministic: \
+             rendered {:?} and then {:?}",
+            side,
+            first,
+            second,
+        );
+        first
+    }
+
+    render(lhs, "left").cmp(&render(rhs, "right"))
+}
+
+/// Compares two values in their `Display` representations, calling `Display::fmt` at most once
+/// for each value.
+///
+/// This yields the same result as [`cmp`], but formats `rhs` into a `String` beforehand and then
+/// compares `lhs` with the string in a single pass. This is preferable to [`cmp`] if `rhs` is
+/// expensive to format, since [`cmp`] may format `rhs` once for each chunk written by `lhs`.
+///
+/// Pass the value that is more expensive to format as `rhs` (and reverse the result if needed).
+///
+/// ## Note
+///
+/// The `Display` implementations may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified `Ordering` value or might even cause
+/// a panic in a future version.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp::cmp_buffered(&format_args!("{}{}", 4, 2), &240).is_gt());
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn cmp_buffered<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    use std::fmt::Write;
+
+    let mut buf = alloc::string::String::new();
+    let _ = write!(buf, "{}", rhs);
+    generic::cmp(lhs, &*buf)
+}
+
+/// Tests two values for equality in their `Display` representations, always by formatting them.
+///
+/// This is the same as [`eq`] except that this never takes the shortcuts for primitive types that
+/// [`eq`] may take with the unstable `fmt_cmp_semver_exempt` configuration. The result is always
+/// the same as [`eq`] for deterministic `Display` implementations, but this may be slower. This is
+/// useful for testing custom `Display` implementations against the byte-wise comparison of their
+/// outputs.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp::generic_eq(&42, "42"));
+/// ```
+#[must_use]
+pub fn generic_eq<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> bool {
+    generic::eq(lhs, rhs)
+}
+
+/// Compares two values in their `Display` representations, always by formatting them.
+///
+/// This is the same as [`cmp`] except that this never takes the shortcuts for primitive types that
+/// [`cmp`] may take with the unstable `fmt_cmp_semver_exempt` configuration, nor the checks of
+/// `debug-checks` feature. The result is always the same as [`cmp`] for deterministic `Display`
+/// implementations, but this may be slower.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp::generic_cmp(&42, &240).is_gt());
+/// ```
+#[must_use]
+pub fn generic_cmp<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    generic::cmp(lhs, rhs)
+}
+
+/// Hashes a value with respect to its `Display` representation, always by formatting it.
+///
+/// This is the same as [`hash`] except that this never takes the shortcuts for primitive types
+/// that [`hash`] may take with the unstable `fmt_cmp_semver_exempt` configuration. The hash value
+/// is always the same as that of [`hash`] for deterministic `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// let mut a = DefaultHasher::new();
+/// fmt_cmp::cmp::generic_hash(&42, &mut a);
+/// let mut b = DefaultHasher::new();
+/// fmt_cmp::hash("42", &mut b);
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+pub fn generic_hash<T: Display + ?Sized, H: Hasher>(hashee: &T, hasher: &mut H) {
+    generic::hash(hashee, hasher)
+}
+
+/// Tests two values for equality in their `Display` representations, returning an error if either
+/// of the `Display` implementations returns an error.
+///
+/// This is the same as [`eq`] except that this reports the errors from the `Display`
+/// implementations instead of returning an unspecified value.
+///
+/// ## Note
+///
+/// Like [`eq`], this may stop formatting the values as soon as the result is determined, so
+/// an error that would be returned after that point is not reported.
+///
+/// ## Example
+///
+/// ```
+/// use std::fmt::{self, Display, Formatter};
+///
+/// struct Failing;
+///
+/// impl Display for Failing {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         f.write_str("hello")?;
+///         Err(fmt::Error)
+///     }
+/// }
+///
+/// assert_eq!(fmt_cmp::try_eq(&Failing, "hello"), Err(fmt::Error));
+/// assert_eq!(fmt_cmp::try_eq("hello", "hello"), Ok(true));
+/// ```
+pub fn try_eq<T: Display + ?Sized, U: Display + ?Sized>(
+    lhs: &T,
+    rhs: &U,
+) -> Result<bool, fmt::Error> {
+    try_cmp(lhs, rhs).map(|ret| ret == Ordering::Equal)
+}
+
+/// Compares two values in their `Display` representations, returning an error if either of the
+/// `Display` implementations returns an error.
+///
+/// This is the same as [`cmp`] except that this reports the errors from the `Display`
+/// implementations instead of returning an unspecified `Ordering` value.
+///
+/// ## Note
+///
+/// Like [`cmp`], this may stop formatting the values as soon as the result is determined, so
+/// an error that would be returned after that point is not reported.
+///
+/// ## Example
+///
+/// ```
+/// use std::fmt::{self, Display, Formatter};
+///
+/// struct Failing;
+///
+/// impl Display for Failing {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         f.write_str("hello")?;
+///         Err(fmt::Error)
+///     }
+/// }
+///
+/// assert_eq!(fmt_cmp::try_cmp(&Failing, "hello"), Err(fmt::Error));
+/// // The result is determined before reaching the error.
+/// assert!(fmt_cmp::try_cmp(&Failing, "help").unwrap().is_lt());
+/// ```
+pub fn try_cmp<T: Display + ?Sized, U: Display + ?Sized>(
+    lhs: &T,
+    rhs: &U,
+) -> Result<Ordering, fmt::Error> {
+    let divergence = generic::diverge(lhs, rhs);
+    if divergence.error {
+        Err(fmt::Error)
+    } else {
+        Ok(divergence.ret)
+    }
+}
+
+/// Returns the length of the longest common prefix of two values' `Display` representations.
+///
+/// This yields the same result as the number of leading bytes shared by `a.to_string()` and
+/// `b.to_string()` without heap allocation, except that the result is rounded down to the nearest
+/// `char` boundary, so that the prefix never ends in the middle of a multi-byte character.
+///
+/// ## Note
+///
+/// This may call `Display::fmt` multiple times and if it emits different strings between the calls,
+/// the resulting value is unspecified.
+///
+/// Also, the `Display` implementations may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified return value or might even cause
+/// a panic in a future version.
+///
+/// ## Examples
+///
+/// ```
+/// assert_eq!(fmt_cmp::common_prefix_len("abracadabra", "abrabanana"), 4);
+/// assert_eq!(fmt_cmp::common_prefix_len(&4200, &42), 2);
+/// ```
+///
+/// The prefix does not split a multi-byte character:
+///
+/// ```
+/// // `"é"` is `[0xC3, 0xA9]` and `"Ã"` is `[0xC3, 0x83]` in UTF-8.
+/// assert_eq!(fmt_cmp::common_prefix_len("é", "Ã"), 0);
+/// ```
+#[must_use]
+pub fn common_prefix_len<T: Display + ?Sized, U: Display + ?Sized>(a: &T, b: &U) -> usize {
+    generic::diverge(a, b).char_pos
+}
+
+/// Compares two values in their `Display` representations, returning the position where they
+/// diverge along with the `Ordering`.
+///
+/// The returned `usize` is the byte index of the first differing byte of `lhs.to_string()` and
+/// `rhs.to_string()`, or the length of the shorter one if it is a prefix of the other, in which
+/// case the `Ordering` tells which side ran out first. Unlike [`common_prefix_len`], the index is
+/// not rounded to a `char` boundary.
+///
+/// ## Note
+///
+/// This may call `Display::fmt` multiple times and if it emits different strings between the calls,
+/// the resulting value is unspecified.
+///
+/// Also, the `Display` implementations may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified return value or might even cause
+/// a panic in a future version.
+///
+/// ## Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(fmt_cmp::cmp_at("abracadabra", "abrabanana"), (Ordering::Greater, 4));
+/// // `"42"` is a prefix of `"4200"`.
+/// assert_eq!(fmt_cmp::cmp_at(&42, &4200), (Ordering::Less, 2));
+/// assert_eq!(fmt_cmp::cmp_at(&42, &42), (Ordering::Equal, 2));
+/// ```
+#[must_use]
+pub fn cmp_at<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> (Ordering, usize) {
+    let divergence = generic::diverge(lhs, rhs);
+    (divergence.ret, divergence.pos)
+}
+
+/// Tests whether the `Display` representation of `haystack` starts with that of `prefix`.
+///
+/// This yields the same result as `haystack.to_string().starts_with(&prefix.to_string())` without
+/// heap allocation.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`] about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::display_starts_with;
+///
+/// assert!(display_starts_with(&4200, &42));
+/// assert!(display_starts_with(&42, &""));
+/// assert!(!display_starts_with(&42, &4200));
+/// ```
+#[must_use]
+pub fn display_starts_with<T: Display + ?Sized, U: Display + ?Sized>(
+    haystack: &T,
+    prefix: &U,
+) -> bool {
+    generic::diverge(prefix, haystack).lhs_is_prefix
+}
+
+/// Tests whether the `Display` representation of `haystack` ends with that of `suffix`.
+///
+/// This yields the same result as `haystack.to_string().ends_with(&suffix.to_string())`.
+///
+/// Since the `Display` representations are streamed from front to back, this formats `suffix`
+/// once into a `String` to know its length and keeps the last bytes of `haystack` in a buffer of
+/// the same length, so this requires the `alloc` feature, unlike [`display_starts_with`].
+///
+/// ## Note
+///
+/// The `Display` implementations may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified return value or might even cause
+/// a panic in a future version.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::display_ends_with;
+///
+/// assert!(display_ends_with(&4200, &200));
+/// assert!(display_ends_with(&42, &""));
+/// assert!(!display_ends_with(&42, &4200));
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn display_ends_with<T: Display + ?Sized, U: Display + ?Sized>(
+    haystack: &T,
+    suffix: &U,
+) -> bool {
+    use alloc::vec;
+    use std::fmt::Write;
+
+    /// Keeps the last `buf.len()` bytes written in a ring buffer.
+    struct Tail {
+        buf: Vec<u8>,
+        /// Total number of bytes written.
+        len: usize,
+    }
+
+    impl Write for Tail {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let n = self.buf.len();
+            let mut s = s.as_bytes();
+            if s.len() > n {
+                // Only the last `n` bytes can remain in the buffer.
+                self.len += s.len() - n;
+                s = &s[s.len() - n..];
+            }
+            for &b in s {
+                self.buf[self.len % n] = b;
+                self.len += 1;
+            }
+            Ok(())
+        }
+    }
+
+    let mut suffix_str = String::new();
+    let _ = write!(suffix_str, "{}", suffix);
+    if suffix_str.is_empty() {
+        return true;
+    }
+
+    let mut tail = Tail {
+        buf: vec![0; suffix_str.len()],
+        len: 0,
+    };
+    let _ = write!(tail, "{}", haystack);
+    if tail.len < tail.buf.len() {
+        return false;
+    }
+
+    // The oldest byte in the buffer is at `tail.len % n`.
+    let (newer, older) = tail.buf.split_at(tail.len % tail.buf.len());
+    let (suffix_older, suffix_newer) = suffix_str.as_bytes().split_at(older.len());
+    older == suffix_older && newer == suffix_newer
+}
+
+/// Hashes a value with respect to its `Display` representation.
+///
+/// This satisfies the same property as `hashee.to_string().hash(hasher)` without heap allocation,
+/// although the exact hash values are not guaranteed to match. In particular, the following variant
+/// of [`Hash` trait's property][hash-and-eq] holds:
+///
+/// ```text
+/// format!("{}", k1) == format!("{}", k2) -> hash(k1) == hash(k2)
+/// ```
+///
+/// This holds regardless of how the `Display` implementations split their output into chunks,
+/// even with a `Hasher` implementation that yields different results for different splits of the
+/// same byte sequence.
+///
+/// The representation is followed by a `0xFF` byte, like `str`'s `Hash` implementation does, to
+/// avoid prefix collisions. Use [`hash_with_terminator`] to choose another terminator.
+///
+/// Consequently, the hash value matches that of `hashee.to_string().hash(hasher)` if the `Hasher`
+/// yields the same result for any split of the same byte sequence into `write` calls and does not
+/// override the unstable `Hasher::write_str` method. This is the case for the `DefaultHasher` of
+/// the standard library at the time of writing, but other hashers may differ, so do not rely on
+/// this when the hasher is not under your control.
+///
+/// ## Note
+///
+/// The `Display` implementation may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified hash value or might even cause
+/// a panic in a future version.
+///
+/// [hash-and-eq]: Hash#hash-and-eq
 pub fn hash<T: Display + ?Sized, H: Hasher>(hashee: &T, hasher: &mut H) {
     imp::hash(hashee, hasher)
 }
 
-#[cfg(test)]
-mod tests {
-    #[cfg(not(feature = "alloc"))]
-    extern crate alloc;
+/// Hashes a value with respect to its `Display` representation, terminating the representation
+/// with the given byte instead of `0xFF`.
+///
+/// This has the same property as [`hash`] does. In addition, the sequence of the bytes fed to the
+/// hasher is prefix-free, i.e., the bytes fed for a value are never a proper prefix of the bytes fed
+/// for another value, so a sequence of values hashed one after another (like the fields of
+/// a struct) cannot collide with another sequence just by shifting the boundaries between the
+/// representations. This is because `terminator` is a byte that never appears in UTF-8.
+///
+/// `hash_with_terminator(value, hasher, 0xFF)` yields the same hash value as
+/// `hash(value, hasher)` unless the unstable `fmt_cmp_semver_exempt` configuration is enabled,
+/// in which case `hash` may feed the hasher differently for some types.
+///
+/// ## Panics
+///
+/// Panics if `terminator` can appear in UTF-8, i.e., if it is not one of `0xC0`, `0xC1` and
+/// `0xF5..=0xFF`.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// fn hash_with_terminator<T: std::fmt::Display>(value: &T, terminator: u8) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     fmt_cmp::cmp::hash_with_terminator(value, &mut hasher, terminator);
+///     hasher.finish()
+/// }
+///
+/// assert_eq!(
+///     hash_with_terminator(&42, 0xC0),
+///     hash_with_terminator(&format_args!("{}{}", 4, 2), 0xC0),
+/// );
+/// ```
+pub fn hash_with_terminator<T: Display + ?Sized, H: Hasher>(
+    hashee: &T,
+    hasher: &mut H,
+    terminator: u8,
+) {
+    if let 0x00..=0xBF | 0xC2..=0xF4 = terminator {
+        panic!("`terminator` must not appear in UTF-8");
+    }
+    generic::hash_with_terminator(hashee, hasher, terminator)
+}
+
+/// Hashes a value with respect to its `Display` representation while writing the representation
+/// to an `io::Write` sink, formatting the value only once.
+///
+/// The hasher is fed the same way as [`hash`] does, so this yields the same hash value as
+/// `hash(value, hasher)`, and the bytes written to `sink` are the same as
+/// `value.to_string().as_bytes()`.
+///
+/// ## Errors
+///
+/// Returns the error from `sink` if writing to it fails. In that case, the hasher may have been
+/// fed a part of the representation and the terminator is not fed.
+///
+/// ## Note
+///
+/// The `Display` implementation may not return error as described by the documentation of
+/// [`std::fmt`]. Doing so would result in an unspecified hash value and an `io::Error` of
+/// `ErrorKind::Other` kind, or might even cause a panic in a future version.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// let value = format_args!("{:X}", 42);
+/// let mut sink = Vec::new();
+/// let mut hasher = DefaultHasher::new();
+/// fmt_cmp::cmp::hash_and_tee(&value, &mut hasher, &mut sink)?;
+/// assert_eq!(sink, b"2A");
+///
+/// let mut expected = DefaultHasher::new();
+/// fmt_cmp::hash(&value, &mut expected);
+/// assert_eq!(hasher.finish(), expected.finish());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn hash_and_tee<T: Display + ?Sized, H: Hasher, W: std::io::Write + ?Sized>(
+    value: &T,
+    hasher: &mut H,
+    sink: &mut W,
+) -> std::io::Result<()> {
+    use std::fmt::Write;
+    use std::io;
+
+    struct Tee<'a, 'b, H, W: ?Sized> {
+        hasher: generic::HashWriter<'a, H>,
+        sink: &'b mut W,
+        /// The error from `sink`, stashed since `fmt::Error` cannot carry it.
+        error: Option<io::Error>,
+    }
+
+    impl<H: Hasher, W: io::Write + ?Sized> Write for Tee<'_, '_, H, W> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if let Err(e) = self.sink.write_all(s.as_bytes()) {
+                self.error = Some(e);
+                return Err(fmt::Error);
+            }
+            self.hasher.write_str(s)
+        }
+    }
+
+    let mut tee = Tee {
+        hasher: generic::HashWriter::new(hasher),
+        sink,
+        error: None,
+    };
+    match write!(tee, "{}", value) {
+        Ok(()) => {
+            tee.hasher.finish();
+            Ok(())
+        }
+        Err(fmt::Error) => Err(tee
+            .error
+            .unwrap_or_else(|| io::Error::new(io::ErrorKind::Other, "formatter error"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::format;
+    use alloc::string::ToString;
+    use std::fmt::{Debug, Formatter};
+
+    use super::*;
+
+    /// Writes the string in chunks of `n` characters (one character per chunk if `n == 0`).
+    #[derive(Debug)]
+    struct SplitFmt<'a>(&'a str, usize);
+
+    impl Display for SplitFmt<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            let SplitFmt(s, n) = *self;
+            let mut pos = 0;
+            s.split_inclusive(|_| {
+                let ret = n == 0 || (pos != 0 && pos % n == 0);
+                pos += 1;
+                ret
+            })
+            .try_for_each(|s| f.write_str(s))
+        }
+    }
+
+    #[test]
+    fn fmt_cmp() {
+        #[track_caller]
+        fn check<T: Debug + Display, U: Debug + Display>(x: T, y: U) {
+            let (x_str, y_str) = (x.to_string(), y.to_string());
+            let expected = x_str.cmp(&y_str);
+
+            assert_eq!(cmp(&x, &y), expected);
+            assert_eq!(cmp(&y, &x), expected.reverse(), "rev");
+            assert_eq!(generic::cmp(&x, &y), expected, "generic");
+            assert_eq!(generic::cmp(&y, &x), expected.reverse(), "generic,rev");
+
+            for s in [&*x_str, &*y_str] {
+                for n in 0..s.len() {
+                    let split = SplitFmt(s, n);
+                    assert_eq!(split.to_string(), s, "`{:?}` is broken", split);
+                }
+            }
+
+            for (nx, ny) in (0..x_str.len()).flat_map(|i| (0..y_str.len()).map(move |j| (i, j))) {
+                let (x, y) = (SplitFmt(&x_str, nx), SplitFmt(&y_str, ny));
+
+                assert_eq!(cmp(&x, &y), expected, "{:?}", (nx, ny));
+                assert_eq!(cmp(&y, &x), expected.reverse(), "{:?},rev", (nx, ny));
+                assert_eq!(generic::cmp(&x, &y), expected, "generic,{:?}", (nx, ny));
+                assert_eq!(
+                    generic::cmp(&y, &x),
+                    expected.reverse(),
+                    "generic,{:?},rev",
+                    (nx, ny)
+                );
+            }
+        }
+
+        // Empty inputs.
+        check("", "");
+
+        // Empty and non-empty inputs.
+        check("", 42);
+
+        // Empty input that writes no chunk at all.
+        check(SplitFmt("", 0), "");
+        check(SplitFmt("", 0), 42);
+
+        // `lhs == rhs && lhs.to_string() == rhs.to_string()`
+        check("abracadabra", "abracadabra");
+
+        // `lhs == rhs && lhs.to_string() != rhs.to_string()`
+        check(0., -0.);
+
+        // `lhs != rhs && lhs.to_string() == rhs.to_string()`
+        check(f64::NAN, f64::NAN);
+
+        // `lhs < rhs && lhs.to_string() > rhs.to_string()`
+        // `lhs.to_string() > rhs.to_string() && lhs.to_string().len() < rhs.to_string().len()`
+        check(42, 240);
+
+        // `lhs > rhs && lhs.to_string() > rhs.to_string()`
+        // `lhs.to_string() > rhs.to_string() && lhs.to_string().len() > rhs.to_string().len()`
+        check(42, 2);
+
+        // One is a prefix of the other.
+        check("abracadabra", "abracad");
+
+        // Have a common prefix.
+        check("abracadabra", "abrabanana");
+    }
+
+    #[test]
+    fn total_order() {
+        use alloc::boxed::Box;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let values: [Box<dyn Display>; 23] = [
+            Box::new(f64::NAN),
+            Box::new(-f64::NAN),
+            Box::new(f64::INFINITY),
+            Box::new(f64::NEG_INFINITY),
+            Box::new(0.0),
+            Box::new(-0.0),
+            Box::new(1.0),
+            Box::new(-1.5),
+            Box::new(1e-7),
+            Box::new(f64::MAX),
+            Box::new(f64::MIN_POSITIVE),
+            Box::new(42),
+            Box::new(240),
+            Box::new(""),
+            Box::new("-"),
+            Box::new("-0"),
+            Box::new("NaN"),
+            Box::new(SplitFmt("NaN", 0)),
+            Box::new(SplitFmt("inf", 1)),
+            Box::new(SplitFmt("-inf", 2)),
+            Box::new("infinity"),
+            Box::new(SplitFmt("0.0000001", 0)),
+            Box::new("é"),
+        ];
+        let strings: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+        let values: Vec<&Cmp> = values.iter().map(|v| Cmp::from_ref(&**v)).collect();
+
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                let ab = a.cmp(b);
+                // Consistency with the string ordering and with `PartialOrd` and `PartialEq`.
+                assert_eq!(ab, strings[i].cmp(&strings[j]), "{:?}", (i, j));
+                assert_eq!(a.partial_cmp(b), Some(ab), "{:?},partial_cmp", (i, j));
+                assert_eq!(a == b, ab == Ordering::Equal, "{:?},eq", (i, j));
+                // Antisymmetry.
+                assert_eq!(b.cmp(a), ab.reverse(), "{:?},antisymmetry", (i, j));
+                // Transitivity.
+                for (k, c) in values.iter().enumerate() {
+                    let bc = b.cmp(c);
+                    if ab == bc || bc == Ordering::Equal {
+                        assert_eq!(a.cmp(c), ab, "{:?},transitivity", (i, j, k));
+                    } else if ab == Ordering::Equal {
+                        assert_eq!(a.cmp(c), bc, "{:?},transitivity", (i, j, k));
+                    }
+                }
+            }
+        }
+
+        let mut sorted = values.clone();
+        sorted.sort_unstable();
+        let mut expected = strings;
+        expected.sort_unstable();
+        assert!(sorted.iter().map(|v| v.to_string()).eq(expected));
+    }
+
+    #[test]
+    fn reflexivity() {
+        #[track_caller]
+        #[allow(clippy::nonminimal_bool)]
+        fn check<T: Display + ?Sized>(x: &Cmp<T>) {
+            assert_eq!(x.cmp(x), Ordering::Equal);
+            assert_eq!(x.partial_cmp(x), Some(Ordering::Equal), "partial_cmp");
+            assert!(x == x, "eq");
+            assert!(x <= x, "le");
+            assert!(x >= x, "ge");
+            assert!(!(x < x), "lt");
+            assert!(!(x > x), "gt");
+        }
+
+        for &x in &[
+            f64::NAN,
+            -f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            0.0,
+            -0.0,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+        ] {
+            check(&Cmp(x));
+            check(&Cmp(x as f32));
+            check(Cmp::from_ref(&x as &dyn Display));
+        }
+        let long = "NaN".repeat(30);
+        for &s in &["", "NaN", "\u{e9}", "\u{10000}", &long] {
+            check(Cmp::from_ref(s));
+            for n in 0..4 {
+                check(&Cmp(SplitFmt(s, n)));
+            }
+        }
+
+        // The `Ord` contract lets `BTreeMap` find a `NaN` key, which is impossible with `f64` keys
+        // even if they were `Ord`.
+        let mut map = alloc::collections::BTreeMap::new();
+        map.insert(Cmp(f64::NAN), 1);
+        map.insert(Cmp(0.0), 2);
+        assert_eq!(map.get(&Cmp(f64::NAN)), Some(&1));
+        assert_eq!(map.get(&Cmp(-0.0)), None);
+    }
+
+    #[test]
+    fn cmp_at() {
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let pos = x
+                .bytes()
+                .zip(y.bytes())
+                .position(|(x, y)| x != y)
+                .unwrap_or_else(|| x.len().min(y.len()));
+            let expected = (x.cmp(y), pos);
+            let expected_rev = (y.cmp(x), pos);
+
+            for (nx, ny) in (0..x.len()).flat_map(|i| (0..y.len()).map(move |j| (i, j))) {
+                let (xs, ys) = (SplitFmt(x, nx), SplitFmt(y, ny));
+                assert_eq!(super::cmp_at(&xs, &ys), expected, "{:?}", (nx, ny));
+                assert_eq!(super::cmp_at(&ys, &xs), expected_rev, "{:?},rev", (nx, ny));
+            }
+        }
+
+        check("", "");
+        check("", "42");
+        check("abracadabra", "abrabanana");
+        check("abracadabra", "abracadabra");
+        check("abra", "abracadabra");
+        check("é", "Ã");
+        check("Ünicode", "Ünicöde");
+    }
+
+    #[test]
+    fn display_starts_with() {
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            for (nx, ny) in (0..=x.len()).flat_map(|i| (0..=y.len()).map(move |j| (i, j))) {
+                let (xs, ys) = (SplitFmt(x, nx), SplitFmt(y, ny));
+                assert_eq!(
+                    super::display_starts_with(&xs, &ys),
+                    x.starts_with(y),
+                    "{:?}",
+                    (nx, ny)
+                );
+                assert_eq!(
+                    super::display_starts_with(&ys, &xs),
+                    y.starts_with(x),
+                    "{:?},rev",
+                    (nx, ny)
+                );
+                #[cfg(feature = "alloc")]
+                {
+                    assert_eq!(
+                        super::display_ends_with(&xs, &ys),
+                        x.ends_with(y),
+                        "{:?},ends_with",
+                        (nx, ny)
+                    );
+                    assert_eq!(
+                        super::display_ends_with(&ys, &xs),
+                        y.ends_with(x),
+                        "{:?},ends_with,rev",
+                        (nx, ny)
+                    );
+                }
+            }
+        }
+
+        check("", "");
+        check("", "42");
+        check("42", "4200");
+        check("200", "4200");
+        check("abracadabra", "abra");
+        check("abracadabra", "abrabanana");
+        check("abracadabra", "abracadabra");
+        check("abracadabra", "cadabra");
+        check("Ünicode", "Ü");
+        check("Ünicode", "ode");
+    }
+
+    #[test]
+    fn common_prefix_len() {
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let mut expected = x
+                .bytes()
+                .zip(y.bytes())
+                .position(|(x, y)| x != y)
+                .unwrap_or_else(|| x.len().min(y.len()));
+            while !x.is_char_boundary(expected) {
+                expected -= 1;
+            }
+
+            for (nx, ny) in (0..x.len()).flat_map(|i| (0..y.len()).map(move |j| (i, j))) {
+                let (xs, ys) = (SplitFmt(x, nx), SplitFmt(y, ny));
+                assert_eq!(
+                    super::common_prefix_len(&xs, &ys),
+                    expected,
+                    "{:?}",
+                    (nx, ny)
+                );
+                assert_eq!(
+                    super::common_prefix_len(&ys, &xs),
+                    expected,
+                    "{:?},rev",
+                    (nx, ny)
+                );
+            }
+        }
+
+        check("", "");
+        check("", "42");
+        check("abracadabra", "abracadabra");
+        check("abracadabra", "abracad");
+        check("abracadabra", "abrabanana");
+        check("4200", "42");
+        check("é", "Ã");
+        check("aé", "aÃ");
+        check("aéb", "aé");
+        check("日本語", "日本人");
+        check("日本語", "日本");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(
+        expected = "the `Display` implementation of the right operand is not deterministic"
+    )]
+    fn checked_cmp_non_deterministic() {
+        use std::cell::Cell;
+
+        /// Displays a different string on every call like a cursor.
+        struct Cursor(Cell<usize>);
+
+        impl Display for Cursor {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let pos = self.0.get();
+                self.0.set(pos + 1);
+                f.write_str(&"abracadabra"[pos..])
+            }
+        }
+
+        assert_eq!(super::checked_cmp("abc", "abc"), Ordering::Equal);
+        assert_eq!(super::checked_cmp(&42, &240), Ordering::Greater);
+        let _ = super::checked_cmp("abc", &Cursor(Cell::new(0)));
+    }
 
-    use alloc::string::ToString;
-    use std::fmt::{Debug, Formatter};
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "`FmtEq` contract violated by")]
+    fn checked_non_deterministic() {
+        use std::cell::Cell;
 
-    use super::*;
+        /// Displays the number of `Display::fmt` calls so far, while comparing equal to any value.
+        #[derive(Clone)]
+        struct Ticks(Cell<usize>);
+
+        impl Display for Ticks {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let n = self.0.get();
+                self.0.set(n + 1);
+                Display::fmt(&n, f)
+            }
+        }
+
+        impl PartialEq for Ticks {
+            fn eq(&self, _: &Self) -> bool {
+                true
+            }
+        }
+
+        impl Eq for Ticks {}
+        impl FmtEq for Ticks {}
+
+        assert_eq!(Cmp::checked("abc"), *"abc");
+        assert_eq!(Cmp::checked(Cmp(f64::NAN)).0, Cmp(f64::NAN));
+        let _ = Cmp::checked(Ticks(Cell::new(0)));
+    }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn fmt_cmp() {
+    fn cmp_buffered() {
+        use std::cell::Cell;
+
+        /// Counts the number of `Display::fmt` calls.
+        struct Counted<'a>(SplitFmt<'a>, Cell<usize>);
+
+        impl Display for Counted<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                self.1.set(self.1.get() + 1);
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let expected = x.cmp(y);
+            for (nx, ny) in (0..=x.len()).flat_map(|i| (0..=y.len()).map(move |j| (i, j))) {
+                let xs = Counted(SplitFmt(x, nx), Cell::new(0));
+                let ys = Counted(SplitFmt(y, ny), Cell::new(0));
+                assert_eq!(super::cmp_buffered(&xs, &ys), expected, "{:?}", (nx, ny));
+                assert_eq!((xs.1.get(), ys.1.get()), (1, 1), "{:?}", (nx, ny));
+            }
+        }
+
+        check("", "");
+        check("", "42");
+        check("42", "");
+        check("42", "240");
+        check("abracadabra", "abrabanana");
+        check("abracadabra", "abracadabra");
+    }
+
+    #[test]
+    fn try_cmp() {
+        /// Writes the string in chunks like `SplitFmt` and then returns an error.
         #[derive(Debug)]
-        struct SplitFmt<'a>(&'a str, usize);
-        impl Display for SplitFmt<'_> {
+        struct Failing<'a>(&'a str, usize);
+
+        impl Display for Failing<'_> {
             fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-                let SplitFmt(s, n) = *self;
-                let mut pos = 0;
-                s.split_inclusive(|_| {
-                    let ret = n == 0 || (pos != 0 && pos % n == 0);
-                    pos += 1;
-                    ret
-                })
-                .try_for_each(|s| f.write_str(s))
+                Display::fmt(&SplitFmt(self.0, self.1), f)?;
+                Err(fmt::Error)
             }
         }
 
         #[track_caller]
-        fn check<T: Debug + Display, U: Debug + Display>(x: T, y: U) {
-            let (x_str, y_str) = (x.to_string(), y.to_string());
-            let expected = x_str.cmp(&y_str);
+        fn check(x: &str, y: &str, expected: Result<Ordering, fmt::Error>) {
+            for (nx, ny) in (0..=x.len()).flat_map(|i| (0..=y.len()).map(move |j| (i, j))) {
+                let (xs, ys) = (SplitFmt(x, nx), SplitFmt(y, ny));
+                let (xf, yf) = (Failing(x, nx), Failing(y, ny));
+                let rev = expected.map(Ordering::reverse);
+                assert_eq!(super::try_cmp(&xf, &ys), expected, "{:?}", (nx, ny));
+                assert_eq!(super::try_cmp(&ys, &xf), rev, "{:?},rev", (nx, ny));
+                assert_eq!(super::try_cmp(&xs, &yf), expected, "{:?},rhs", (nx, ny));
+                assert_eq!(super::try_cmp(&yf, &xs), rev, "{:?},rhs,rev", (nx, ny));
+                assert_eq!(super::try_cmp(&xf, &yf), expected, "{:?},both", (nx, ny));
+                assert_eq!(super::try_eq(&xf, &ys), expected.map(Ordering::is_eq));
 
-            assert_eq!(cmp(&x, &y), expected);
-            assert_eq!(cmp(&y, &x), expected.reverse(), "rev");
-            assert_eq!(generic::cmp(&x, &y), expected, "generic");
-            assert_eq!(generic::cmp(&y, &x), expected.reverse(), "generic,rev");
+                let ok = Ok(x.cmp(y));
+                assert_eq!(super::try_cmp(&xs, &ys), ok, "{:?},ok", (nx, ny));
+                assert_eq!(super::try_eq(&xs, &ys), ok.map(Ordering::is_eq));
+            }
+        }
 
-            for s in [&*x_str, &*y_str] {
-                for n in 0..s.len() {
-                    let split = SplitFmt(s, n);
-                    assert_eq!(split.to_string(), s, "`{:?}` is broken", split);
+        // The error is reached.
+        check("", "", Err(fmt::Error));
+        check("abracadabra", "abracadabra", Err(fmt::Error));
+
+        // The result is determined before reaching the error.
+        check("abracadabra", "abrabanana", Ok(Ordering::Greater));
+        check("abrabanana", "abracadabra", Ok(Ordering::Less));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_matches_string_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        #[track_caller]
+        fn check<T: Display + ?Sized>(value: &T) {
+            let mut expected = DefaultHasher::new();
+            value.to_string().hash(&mut expected);
+            let mut hasher = DefaultHasher::new();
+            hash(value, &mut hasher);
+            assert_eq!(hasher.finish(), expected.finish(), "{}", value);
+            let mut hasher = DefaultHasher::new();
+            generic::hash(value, &mut hasher);
+            assert_eq!(hasher.finish(), expected.finish(), "{},generic", value);
+        }
+
+        check("");
+        check("abc");
+        check(&42);
+        check(&-1.5);
+        check(&SplitFmt("abracadabra", 0));
+        check(&SplitFmt("abracadabra", 3));
+        check(&"0123456789abcdef".repeat(20));
+        check(&SplitFmt(&"Ünicode ".repeat(20), 5));
+    }
+
+    #[test]
+    fn hash_is_split_invariant() {
+        use alloc::vec::Vec;
+
+        use crate::testing::Recorder;
+
+        fn record<T: Display + ?Sized>(value: &T) -> Vec<Vec<u8>> {
+            let mut hasher = Recorder::default();
+            super::hash(value, &mut hasher);
+            hasher.0
+        }
+
+        fn record_generic<T: Display + ?Sized>(value: &T) -> Vec<Vec<u8>> {
+            let mut hasher = Recorder::default();
+            generic::hash(value, &mut hasher);
+            hasher.0
+        }
+
+        #[track_caller]
+        fn check(s: &str) {
+            let expected = record_generic(s);
+            for n in 0..=s.len() {
+                assert_eq!(record(&SplitFmt(s, n)), expected, "{}", n);
+                assert_eq!(record_generic(&SplitFmt(s, n)), expected, "{},generic", n);
+            }
+        }
+
+        check("");
+        check("a");
+        check("abracadabra");
+        check("Ünicode");
+        check(&"0123456789abcdef".repeat(8));
+        check(&"0123456789abcdef".repeat(8)[1..]);
+        check(&"Ünicode ".repeat(20));
+
+        // Different strings never collide in the sequence of bytes fed to the hasher.
+        let flatten = |s: &str| record(s).concat();
+        assert_ne!(flatten("ab"), flatten("a"));
+        assert_ne!(flatten("\u{ff}"), flatten(""));
+    }
+
+    #[test]
+    fn custom_terminator() {
+        use alloc::vec::Vec;
+
+        use crate::testing::Recorder;
+
+        fn fnv<T: Display + ?Sized>(value: &T, terminator: u8) -> (Vec<u8>, u64) {
+            let mut hasher = Recorder::default();
+            hash_with_terminator(value, &mut hasher, terminator);
+            (hasher.bytes(), hasher.finish())
+        }
+
+        for &s in &["", "a", "abracadabra", "Ünicode"] {
+            let (ff, hash_ff) = fnv(s, 0xff);
+            let (c0, hash_c0) = fnv(s, 0xc0);
+            assert_eq!(ff, [s.as_bytes(), &[0xff]].concat());
+            assert_eq!(c0, [s.as_bytes(), &[0xc0]].concat());
+            assert_ne!(hash_ff, hash_c0);
+
+            let mut hasher = Recorder::default();
+            generic::hash(s, &mut hasher);
+            assert_eq!(hasher.finish(), hash_ff);
+
+            for n in 0..=s.len() {
+                assert_eq!(fnv(&SplitFmt(s, n), 0xff).1, hash_ff, "{}", n);
+                assert_eq!(fnv(&SplitFmt(s, n), 0xc0).1, hash_c0, "{}", n);
+            }
+        }
+
+        assert_ne!(fnv("ab", 0xf5).0, fnv("a", 0xf5).0);
+    }
+
+    #[test]
+    #[should_panic(expected = "`terminator` must not appear in UTF-8")]
+    fn utf8_terminator() {
+        let mut hasher = crate::testing::Recorder::default();
+        hash_with_terminator("a", &mut hasher, b'\n');
+    }
+
+    #[test]
+    fn cmp_str() {
+        #[track_caller]
+        fn check(x: u32, y: &str) {
+            let expected = x.to_string().as_str().cmp(y);
+            let x = Cmp(x);
+            assert_eq!(x.partial_cmp(y), Some(expected));
+            assert_eq!(x.partial_cmp(&y), Some(expected), "&str");
+            assert_eq!(y.partial_cmp(&x), Some(expected.reverse()), "rev");
+            assert_eq!((&y).partial_cmp(&x), Some(expected.reverse()), "&str,rev");
+            assert_eq!(x == *y, expected.is_eq());
+            assert_eq!(x == y, expected.is_eq(), "&str");
+            assert_eq!(*y == x, expected.is_eq(), "rev");
+            assert_eq!(y == x, expected.is_eq(), "&str,rev");
+            #[cfg(feature = "alloc")]
+            {
+                let y = alloc::string::String::from(y);
+                assert_eq!(x.partial_cmp(&y), Some(expected), "String");
+                assert_eq!(y.partial_cmp(&x), Some(expected.reverse()), "String,rev");
+                assert_eq!(x == y, expected.is_eq(), "String");
+                assert_eq!(y == x, expected.is_eq(), "String,rev");
+            }
+        }
+
+        check(42, "42");
+        check(42, "240");
+        check(42, "4");
+        check(42, "");
+        check(0, "0");
+    }
+
+    #[test]
+    fn display_forwards_flags() {
+        assert_eq!(format!("{:>6}", Cmp(42)), "    42");
+        assert_eq!(format!("{:<6}|", Cmp("foo")), "foo   |");
+        assert_eq!(format!("{:^7}", Cmp('x')), "   x   ");
+        assert_eq!(format!("{:+05}", Cmp(42)), "+0042");
+        assert_eq!(format!("{:.2}", Cmp(1.0)), "1.00");
+        assert_eq!(format!("{:.2}", Cmp("foo")), "fo");
+    }
+
+    #[test]
+    fn alternate_form() {
+        /// Pretty-prints a list on `{:#}` like `Debug` does.
+        struct List<'a>(&'a [u32]);
+
+        impl Display for List<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let sep = if f.alternate() { ",\n" } else { ", " };
+                for (i, x) in self.0.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(sep)?;
+                    }
+                    write!(f, "{}", x)?;
                 }
+                Ok(())
             }
+        }
 
-            for (nx, ny) in (0..x_str.len()).flat_map(|i| (0..y_str.len()).map(move |j| (i, j))) {
-                let (x, y) = (SplitFmt(&x_str, nx), SplitFmt(&y_str, ny));
+        let (x, y) = (List(&[1, 2]), List(&[1]));
+        assert_eq!(format!("{:#}", Cmp(&x)), format!("{:#}", x));
+        assert_eq!(format!("{:#}", Cmp(&x)), "1,\n2");
+        assert_eq!(format!("{}", Cmp(&x)), "1, 2");
 
-                assert_eq!(cmp(&x, &y), expected, "{:?}", (nx, ny));
-                assert_eq!(cmp(&y, &x), expected.reverse(), "{:?},rev", (nx, ny));
-                assert_eq!(generic::cmp(&x, &y), expected, "generic,{:?}", (nx, ny));
-                assert_eq!(
-                    generic::cmp(&y, &x),
-                    expected.reverse(),
-                    "generic,{:?},rev",
-                    (nx, ny)
-                );
+        // The comparison uses the non-alternate form even if `Cmp` is displayed in the alternate
+        // form.
+        assert!(Cmp(&x) == *"1, 2");
+        assert!(Cmp(&x) != *"1,\n2");
+        assert!(Cmp(&x) > Cmp(&y));
+        // `"1, 2" > "1,\n2"` since `' ' > '\n'`.
+        assert_eq!(super::cmp(&x, "1,\n2"), Ordering::Greater);
+        assert_eq!(
+            super::cmp(&format_args!("{:#}", x), "1,\n2"),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn display_debug() {
+        #[track_caller]
+        fn check(s: &str) {
+            let expected = format!("Cmp({:?})", s);
+            assert_eq!(format!("{:?}", Cmp(s).display_debug()), expected);
+            assert_eq!(
+                format!("{:?}", Cmp(SplitFmt(s, 0)).display_debug()),
+                expected,
+                "split"
+            );
+        }
+
+        check("");
+        check("foo");
+        check("\"quoted\" 'single'");
+        check("tab\tnewline\n\\");
+        check("\u{0}\u{7f}é\u{301}");
+        assert_eq!(format!("{:?}", Cmp(42).display_debug()), r#"Cmp("42")"#);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn binary_heap_of_trait_objects() {
+        use alloc::boxed::Box;
+        use alloc::collections::BinaryHeap;
+        use alloc::vec::Vec;
+        use std::iter;
+
+        let values: [&dyn Display; 6] = [&42, &"foo", &240, &'4', &"", &format_args!("{:x}", 255)];
+        let mut heap: BinaryHeap<Box<Cmp>> = BinaryHeap::new();
+        for &value in &values {
+            let boxed: Box<dyn Display> = Box::new(value.to_string());
+            heap.push(boxed.into());
+        }
+
+        let mut expected: Vec<_> = values.iter().map(ToString::to_string).collect();
+        expected.sort_unstable_by(|lhs, rhs| rhs.cmp(lhs));
+        let popped: Vec<_> = iter::from_fn(|| heap.pop())
+            .map(|value| value.to_string())
+            .collect();
+        assert_eq!(popped, expected);
+    }
+
+    #[test]
+    fn min_max_clamp() {
+        use crate::testing::Key;
+
+        assert_eq!(Cmp(9).max(Cmp(10)), Cmp(9));
+        assert_eq!(Cmp(9).min(Cmp(10)), Cmp(10));
+        // Ties are resolved like `Ord::max` and `Ord::min`.
+        assert_eq!(Cmp(Key(1, 0)).max(Cmp(Key(1, 1))).0, Key(1, 1));
+        assert_eq!(Cmp(Key(1, 0)).min(Cmp(Key(1, 1))).0, Key(1, 0));
+
+        for &(x, expected) in &[(1, 2), (2, 2), (25, 25), (3, 3), (4, 3), (100, 2), (0, 2)] {
+            assert_eq!(Cmp(x).clamp(Cmp(2), Cmp(3)), Cmp(expected), "{}", x);
+            assert_eq!(Cmp(x).clamp_display(2, 3), Cmp(expected), "{}", x);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_inverted() {
+        let _ = Cmp(25).clamp_display(3, 2);
+    }
+
+    #[test]
+    fn as_dyn() {
+        let mut values = [
+            &Cmp(42_u8) as &Cmp<dyn Display>,
+            Cmp("foo").as_dyn(),
+            Cmp(3.25_f64).as_dyn(),
+            Cmp(SplitFmt("4", 0)).as_dyn(),
+        ];
+        values.sort();
+        assert_eq!(
+            values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<alloc::vec::Vec<_>>(),
+            ["3.25", "4", "42", "foo"]
+        );
+        assert!(*values[1] == Cmp(4));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_set() {
+        use std::collections::HashSet;
+
+        // Equal in `Display` but not in `PartialEq`.
+        let set: HashSet<_> = vec![Cmp(f64::NAN), Cmp(f64::NAN)].into_iter().collect();
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&Cmp(f64::NAN)));
+        assert!(set.contains(&Cmp(-f64::NAN)));
+
+        // Equal in `PartialEq` but not in `Display`.
+        assert_eq!(0.0, -0.0);
+        let set: HashSet<_> = vec![Cmp(0.0), Cmp(-0.0)].into_iter().collect();
+        assert_eq!(set.len(), 2);
+
+        // Equal in `Display` but written in different chunks.
+        let values: Vec<Box<dyn Display>> = vec![
+            Box::new(f64::NAN),
+            Box::new("NaN"),
+            Box::new(SplitFmt("NaN", 0)),
+            Box::new(SplitFmt("NaN", 2)),
+            Box::new(format_args!("{}{}", "N", "aN").to_string()),
+            Box::new(-0.0),
+            Box::new(SplitFmt("-0", 0)),
+            Box::new(0),
+        ];
+        let set: HashSet<_> = values.into_iter().map(Cmp).collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&Cmp(Box::new("-0") as Box<dyn Display>)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_and_tee() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::io;
+
+        #[track_caller]
+        fn check(s: &str) {
+            let value = SplitFmt(s, 3);
+            let mut sink = Vec::new();
+            let mut hasher = DefaultHasher::new();
+            super::hash_and_tee(&value, &mut hasher, &mut sink).unwrap();
+            assert_eq!(sink, s.as_bytes());
+
+            let mut expected = DefaultHasher::new();
+            hash(&value, &mut expected);
+            assert_eq!(hasher.finish(), expected.finish());
+        }
+
+        check("");
+        check("foo");
+        check(&"0123456789abcdef".repeat(20));
+
+        /// A sink that fails after accepting `n` bytes.
+        struct Limited(usize);
+
+        impl io::Write for Limited {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.0 == 0 {
+                    return Err(io::Error::new(io::ErrorKind::WriteZero, "full"));
+                }
+                let n = buf.len().min(self.0);
+                self.0 -= n;
+                Ok(n)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
             }
         }
 
-        // Empty inputs.
-        check("", "");
+        let mut hasher = DefaultHasher::new();
+        let e =
+            super::hash_and_tee(&SplitFmt("foobar", 2), &mut hasher, &mut Limited(3)).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::WriteZero);
+    }
 
-        // Empty and non-empty inputs.
-        check("", 42);
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn from_fmt() {
+        assert_eq!(
+            Cmp::from_fmt(format_args!("{:X}", 42)),
+            Cmp("2A".to_string())
+        );
+        assert_eq!(Cmp::from_fmt(format_args!("")).0, "");
+        let keys = [
+            Cmp::from_fmt(format_args!("{}-{}", 4, 2)),
+            Cmp::from_fmt(format_args!("{}", 240)),
+        ];
+        assert!(keys[0] > keys[1]);
+    }
 
-        // `lhs == rhs && lhs.to_string() == rhs.to_string()`
-        check("abracadabra", "abracadabra");
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cow_variants() {
+        use alloc::borrow::Cow;
+        use alloc::string::String;
 
-        // `lhs == rhs && lhs.to_string() != rhs.to_string()`
-        check(0., -0.);
+        use crate::testing::hash_of;
 
-        // `lhs != rhs && lhs.to_string() == rhs.to_string()`
-        check(f64::NAN, f64::NAN);
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let expected = x.cmp(y);
+            fn variants(s: &str) -> [Cow<'_, str>; 2] {
+                [Cow::Borrowed(s), Cow::Owned(String::from(s))]
+            }
 
-        // `lhs < rhs && lhs.to_string() > rhs.to_string()`
-        // `lhs.to_string() > rhs.to_string() && lhs.to_string().len() < rhs.to_string().len()`
-        check(42, 240);
+            for a in &variants(x) {
+                for b in &variants(y) {
+                    let (ca, cb) = (Cmp::from_ref(a), Cmp::from_ref(b));
+                    assert_eq!(ca.cmp(cb), expected, "{:?}", (a, b));
+                    assert_eq!(ca == cb, expected == Ordering::Equal, "{:?},eq", (a, b));
+                    assert_eq!(cmp(a, b), expected, "{:?},cmp", (a, b));
+                    assert_eq!(generic::cmp(a, b), expected, "{:?},generic", (a, b));
+                    assert_eq!(a.cmp(b), expected, "{:?},Ord", (a, b));
+                    #[cfg(feature = "cmp-display")]
+                    assert_eq!(
+                        CmpDisplay::cmp_display(a, b),
+                        expected,
+                        "{:?},CmpDisplay",
+                        (a, b)
+                    );
+                    if expected == Ordering::Equal {
+                        assert_eq!(hash_of(ca), hash_of(cb), "{:?},hash", (a, b));
+                    }
+                }
+            }
+        }
 
-        // `lhs > rhs && lhs.to_string() > rhs.to_string()`
-        // `lhs.to_string() > rhs.to_string() && lhs.to_string().len() > rhs.to_string().len()`
-        check(42, 2);
+        check("abc", "abc");
+        check("abc", "abd");
+        check("", "abc");
+        check("42", "240");
 
-        // One is a prefix of the other.
-        check("abracadabra", "abracad");
+        assert!(Cmp(Cow::Borrowed("abc")) == Cmp(Cow::<str>::Owned(String::from("abc"))));
+        assert!(Cmp(Cow::Borrowed("abc")) < Cmp(Cow::<str>::Owned(String::from("abd"))));
+    }
 
-        // Have a common prefix.
-        check("abracadabra", "abrabanana");
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn borrow_str() {
+        use alloc::boxed::Box;
+        use alloc::collections::{BTreeMap, BTreeSet};
+        use alloc::string::String;
+
+        let map: BTreeMap<Cmp<String>, u32> = ["abc", "42", "240", "def"]
+            .iter()
+            .enumerate()
+            .map(|(i, &k)| (Cmp(String::from(k)), i as u32))
+            .collect();
+        assert_eq!(map.get(Cmp::from_ref("abc")), Some(&0));
+        assert_eq!(map.get(Cmp::from_ref("240")), Some(&2));
+        assert_eq!(map.get(Cmp::from_ref("ab")), None);
+
+        let set: BTreeSet<Cmp<Box<str>>> = ["abc", "def"].iter().map(|&k| Cmp(k.into())).collect();
+        assert!(set.contains(Cmp::from_ref("def")));
+        assert!(!set.contains(Cmp::from_ref("ghi")));
+
+        #[cfg(feature = "std")]
+        {
+            use std::collections::HashMap;
+
+            let map: HashMap<Cmp<String>, u32> = map.into_iter().collect();
+            assert_eq!(map.get(Cmp::from_ref("def")), Some(&3));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn btree_range() {
+        use alloc::collections::{btree_map, BTreeMap};
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let map: BTreeMap<Cmp<String>, u32> = ["abc", "42", "240", "def", "m", "mno", "xyz"]
+            .iter()
+            .map(|&k| (Cmp(String::from(k)), 0))
+            .collect();
+        fn keys<'a>(range: btree_map::Range<'a, Cmp<String>, u32>) -> Vec<&'a str> {
+            range.map(|(k, _)| &*k.0).collect()
+        }
+
+        let (a, m) = (Cmp::from_ref("a"), Cmp::from_ref("m"));
+        assert_eq!(keys(map.range::<Cmp<str>, _>(a..m)), ["abc", "def"]);
+        assert_eq!(keys(map.range::<Cmp<str>, _>(a..=m)), ["abc", "def", "m"]);
+        assert_eq!(keys(map.range::<Cmp<str>, _>(m..)), ["m", "mno", "xyz"]);
+        assert_eq!(keys(map.range::<Cmp<str>, _>(..a)), ["240", "42"]);
+        assert_eq!(
+            keys(map.range::<Cmp<str>, _>(..=m)),
+            ["240", "42", "abc", "def", "m"]
+        );
+        // `"240" < "3" < "42"`
+        let (x, y) = (Cmp::from_ref("3"), Cmp::from_ref("9"));
+        assert_eq!(keys(map.range::<Cmp<str>, _>(x..y)), ["42"]);
+    }
+
+    #[test]
+    fn boxed_dyn_btree_set() {
+        use alloc::boxed::Box;
+        use alloc::collections::BTreeSet;
+        use alloc::string::{String, ToString};
+        use alloc::vec::Vec;
+
+        let mut set: BTreeSet<Box<Cmp>> = BTreeSet::new();
+        assert!(set.insert(Box::new(Cmp(240_u32))));
+        assert!(set.insert(Box::new(Cmp(String::from("abc")))));
+        assert!(set.insert(Box::new(Cmp(4.25_f64))));
+        assert!(set.insert(Box::new(Cmp(-1_i8))));
+        assert!(set.insert(Box::new(Cmp(f64::NAN))));
+        // Same representation as `240_u32`.
+        assert!(!set.insert(Box::new(Cmp("240"))));
+        let values: Vec<_> = set.iter().map(|v| v.to_string()).collect();
+        assert_eq!(values, ["-1", "240", "4.25", "NaN", "abc"]);
+        assert!(set.contains(&(Box::new(Cmp(4.25_f32)) as Box<Cmp>)));
+        assert!(!set.contains(&(Box::new(Cmp(42)) as Box<Cmp>)));
+    }
+
+    #[test]
+    fn cmp_reverse() {
+        let values = [
+            &"" as &dyn Display,
+            &42,
+            &240,
+            &"abracadabra",
+            &"abrabanana",
+        ];
+        for x in &values {
+            for y in &values {
+                let (x, y) = (<CmpReverse>::from_ref(*x), <CmpReverse>::from_ref(*y));
+                assert_eq!(x.cmp(y), cmp(&x.0, &y.0).reverse());
+                assert_eq!(x == y, eq(&x.0, &y.0));
+            }
+        }
     }
 
     #[test]
@@ -342,6 +2592,8 @@ mod tests {
         #[cfg(feature = "alloc")]
         {
             let _ = Cmp::from_boxed(alloc::boxed::Box::new(1)).into_boxed_inner();
+            let _ = Cmp::from_rc(alloc::rc::Rc::new(1)).into_rc_inner();
+            let _ = Cmp::from_arc(alloc::sync::Arc::new(1)).into_arc_inner();
         }
 
         // ZST
@@ -349,6 +2601,8 @@ mod tests {
         #[cfg(feature = "alloc")]
         {
             let _ = Cmp::from_boxed(alloc::boxed::Box::new(std::fmt::Error)).into_boxed_inner();
+            let _ = Cmp::from_rc(alloc::rc::Rc::new(std::fmt::Error)).into_rc_inner();
+            let _ = Cmp::from_arc(alloc::sync::Arc::new(std::fmt::Error)).into_arc_inner();
         }
 
         // DST
@@ -357,6 +2611,15 @@ mod tests {
         {
             let _ = Cmp::from_boxed(alloc::string::String::from("hello").into_boxed_str())
                 .into_boxed_inner();
+            let _ = Cmp::from_rc(alloc::rc::Rc::<str>::from("hello")).into_rc_inner();
+            let _ = Cmp::from_arc(alloc::sync::Arc::<str>::from("hello")).into_arc_inner();
+
+            // Shared ownership is preserved.
+            let rc = alloc::rc::Rc::<str>::from("hello");
+            let cmp = Cmp::from_rc(rc.clone());
+            assert_eq!(alloc::rc::Rc::strong_count(&rc), 2);
+            drop(cmp);
+            assert_eq!(alloc::rc::Rc::strong_count(&rc), 1);
         }
 
         // Trait object
@@ -364,6 +2627,116 @@ mod tests {
         #[cfg(feature = "alloc")]
         {
             let _ = <Cmp>::from_boxed(alloc::boxed::Box::new(1)).into_boxed_inner();
+            let _ = <Cmp>::from_rc(alloc::rc::Rc::new(1)).into_rc_inner();
+            let _ = <Cmp>::from_arc(alloc::sync::Arc::new(1)).into_arc_inner();
+        }
+
+        // `CmpReverse`
+        let _ = CmpReverse::from_ref(&1);
+        let _ = CmpReverse::from_ref(&std::fmt::Error);
+        let _ = CmpReverse::from_ref("hello");
+        let _ = <CmpReverse>::from_ref(&1);
+        #[cfg(feature = "alloc")]
+        {
+            let _ = CmpReverse::from_boxed(alloc::boxed::Box::new(1)).into_boxed_inner();
+            let _ =
+                CmpReverse::from_boxed(alloc::boxed::Box::new(std::fmt::Error)).into_boxed_inner();
+            let _ = CmpReverse::from_boxed(alloc::string::String::from("hello").into_boxed_str())
+                .into_boxed_inner();
+            let _ = <CmpReverse>::from_boxed(alloc::boxed::Box::new(1)).into_boxed_inner();
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_clone() {
+        use alloc::boxed::Box;
+
+        let x = Cmp::from_boxed(Box::<str>::from("hello"));
+        let y = x.clone();
+        assert_eq!(x, y);
+        assert_eq!(&y.0, "hello");
+        // A fresh allocation.
+        assert!(!std::ptr::eq(&x.0, &y.0));
+
+        let x = Cmp::from_boxed(Box::new(42_u32));
+        let y = x.clone();
+        assert_eq!(x, y);
+    }
+
+    /// Writes the first `n` bytes of the string, and then panics.
+    struct PanicAfter(&'static str, usize);
+
+    impl Display for PanicAfter {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            Display::fmt(&SplitFmt(&self.0[..self.1], 1), f)?;
+            panic!("`PanicAfter` panicked");
+        }
+    }
+
+    // The `soundness_` prefix makes the CI run these under Miri, which would catch a double free or
+    // a leak while unwinding.
+
+    #[test]
+    #[should_panic(expected = "`PanicAfter` panicked")]
+    fn soundness_panic_in_cmp() {
+        let _ = cmp(&PanicAfter("abc", 2), &SplitFmt("abcd", 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "`PanicAfter` panicked")]
+    fn soundness_panic_in_cmp_rhs() {
+        let _ = cmp(&SplitFmt("abcd", 1), &PanicAfter("abc", 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "`PanicAfter` panicked")]
+    fn soundness_panic_in_hash() {
+        let mut hasher = crate::testing::Recorder::default();
+        hash(&PanicAfter("abc", 2), &mut hasher);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "`PanicAfter` panicked")]
+    fn soundness_panic_in_boxed_dyn() {
+        use alloc::boxed::Box;
+        use alloc::string::String;
+
+        let x = Cmp::from_boxed(Box::new(PanicAfter("abc", 1)) as Box<dyn Display>);
+        let y = Cmp::from_boxed(Box::new(String::from("abd")) as Box<dyn Display>);
+        // Both boxes are dropped while unwinding from here.
+        let _ = x < y;
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use alloc::collections::BTreeSet;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        #[track_caller]
+        fn check<T>(value: T, json: &str)
+        where
+            T: Debug + Display + serde::Serialize + serde::de::DeserializeOwned,
+        {
+            let value = Cmp(value);
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Cmp<T>>(json).unwrap(), value);
         }
+
+        check(42_u32, "42");
+        check(String::from("hello"), "\"hello\"");
+
+        let set: BTreeSet<Cmp<u32>> = (1..=10).map(Cmp).collect();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, "[1,10,2,3,4,5,6,7,8,9]");
+        assert_eq!(
+            serde_json::from_str::<BTreeSet<Cmp<u32>>>(&json).unwrap(),
+            set
+        );
+        let vec = serde_json::from_str::<Vec<u32>>(&json).unwrap();
+        assert!(vec.into_iter().map(Cmp).eq(set));
     }
 }
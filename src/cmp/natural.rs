@@ -0,0 +1,332 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+
+use super::cmp;
+
+/// Compares two values in their `Display` representations in the "natural" order, where runs of
+/// ASCII digits are compared by their numerical values.
+///
+/// The representations are compared segment by segment, where a segment is either a maximal run
+/// of ASCII digits or a single byte of the other characters:
+///
+/// - Two runs of digits are compared by their numerical values, e.g., `"file2" < "file10"`. This
+///   never parses the digits into an integer, so a run may be arbitrarily long. If the values are
+///   equal, the run with fewer leading zeros is less, e.g., `"a1" < "a01"`, which makes this a
+///   total order where only the identical representations compare equal.
+/// - Otherwise, the segments are compared byte-wise like [`cmp`](super::cmp()) does, e.g.,
+///   `"a1" < "b"` and `"a!" < "a1"`.
+///
+/// This does not allocate on the heap. However, each run of more than 64 digits (after the leading
+/// zeros) makes the value be formatted once more to measure the length of the run.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::cmp_natural;
+///
+/// let mut files = ["file2", "file10", "file1"];
+/// files.sort_by(cmp_natural);
+/// assert_eq!(files, ["file1", "file2", "file10"]);
+/// ```
+#[must_use]
+pub fn cmp_natural<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    cmp(&Natural(lhs), &Natural(rhs))
+}
+
+/// A `Display` adapter that transforms the representation into one whose lexicographic order is
+/// the natural order of the original representation.
+///
+/// Each run of digits is replaced with `'0'` (which keeps the order against the other
+/// characters), the length of the run excluding the leading zeros, the digits excluding the
+/// leading zeros and the number of the leading zeros, where the numbers are encoded with
+/// `write_number`. Since the transformation is injective, the identical representations are the
+/// only ones that compare equal.
+struct Natural<'a, T: ?Sized>(&'a T);
+
+/// Number of significant digits to buffer until the end of a run is found.
+const BUF: usize = 64;
+
+struct Run {
+    leading_zeros: usize,
+    /// Byte position of the first significant digit of the run.
+    start: usize,
+    buf: [u8; BUF],
+    len: usize,
+    /// Whether the header of the run has been written, in which case the significant digits are
+    /// written without buffering.
+    streaming: bool,
+}
+
+struct Adapter<'a, 'b, T: ?Sized> {
+    value: &'a T,
+    f: &'a mut Formatter<'b>,
+    /// Byte position in the original representation.
+    pos: usize,
+    run: Option<Run>,
+}
+
+impl<T: Display + ?Sized> Display for Natural<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut adapter = Adapter {
+            value: self.0,
+            f,
+            pos: 0,
+            run: None,
+        };
+        write!(adapter, "{}", self.0)?;
+        adapter.end_run()
+    }
+}
+
+impl<T: Display + ?Sized> Adapter<'_, '_, T> {
+    fn push_digits(&mut self, mut digits: &str) -> fmt::Result {
+        let run = self.run.as_mut().unwrap();
+        if run.len == 0 && !run.streaming {
+            let zeros = digits.bytes().take_while(|&b| b == b'0').count();
+            run.leading_zeros += zeros;
+            digits = &digits[zeros..];
+            run.start = self.pos + zeros;
+        }
+
+        if run.streaming {
+            return self.f.write_str(digits);
+        }
+        if let Some(buf) = run.buf.get_mut(run.len..run.len + digits.len()) {
+            buf.copy_from_slice(digits.as_bytes());
+            run.len += digits.len();
+            return Ok(());
+        }
+
+        // The run is too long to buffer.
+        let len = measure_run(self.value, run.start);
+        run.streaming = true;
+        self.f.write_char('0')?;
+        write_number(self.f, len)?;
+        // Safety: `buf[..len]` consists of ASCII digits.
+        self.f
+            .write_str(unsafe { std::str::from_utf8_unchecked(&run.buf[..run.len]) })?;
+        self.f.write_str(digits)
+    }
+
+    fn end_run(&mut self) -> fmt::Result {
+        let run = if let Some(run) = self.run.take() {
+            run
+        } else {
+            return Ok(());
+        };
+        if !run.streaming {
+            self.f.write_char('0')?;
+            write_number(self.f, run.len)?;
+            // Safety: `buf[..len]` consists of ASCII digits.
+            self.f
+                .write_str(unsafe { std::str::from_utf8_unchecked(&run.buf[..run.len]) })?;
+        }
+        write_number(self.f, run.leading_zeros)
+    }
+}
+
+impl<T: Display + ?Sized> Write for Adapter<'_, '_, T> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut rest = s;
+        while !rest.is_empty() {
+            if self.run.is_some() {
+                let n = rest.bytes().take_while(u8::is_ascii_digit).count();
+                self.push_digits(&rest[..n])?;
+                self.pos += n;
+                rest = &rest[n..];
+                if !rest.is_empty() {
+                    self.end_run()?;
+                }
+            } else {
+                let n = rest
+                    .bytes()
+                    .position(|b| b.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                self.f.write_str(&rest[..n])?;
+                self.pos += n;
+                rest = &rest[n..];
+                if !rest.is_empty() {
+                    self.run = Some(Run {
+                        leading_zeros: 0,
+                        start: self.pos,
+                        buf: [0; BUF],
+                        len: 0,
+                        streaming: false,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the length of the run of digits starting at `start` in the `Display` representation of
+/// `value`.
+fn measure_run<T: Display + ?Sized>(value: &T, start: usize) -> usize {
+    struct Measure {
+        start: usize,
+        pos: usize,
+        len: usize,
+    }
+
+    impl Write for Measure {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let skip = self.start.saturating_sub(self.pos).min(s.len());
+            self.pos += s.len();
+            let s = &s.as_bytes()[skip..];
+            let n = s.iter().take_while(|b| b.is_ascii_digit()).count();
+            self.len += n;
+            if n < s.len() {
+                // Reached the end of the run.
+                return Err(fmt::Error);
+            }
+            Ok(())
+        }
+    }
+
+    let mut measure = Measure {
+        start,
+        pos: 0,
+        len: 0,
+    };
+    let _ = write!(measure, "{}", value);
+    measure.len
+}
+
+/// Writes a number in a representation whose lexicographic order agrees with the numerical order,
+/// i.e., the number of digits (as a single character) followed by the digits.
+fn write_number(f: &mut Formatter<'_>, n: usize) -> fmt::Result {
+    let mut digits = 1;
+    let mut m = n;
+    while m >= 10 {
+        m /= 10;
+        digits += 1;
+    }
+    // `digits <= 20`, so this never goes past `'D'`.
+    write!(f, "{}{}", char::from(b'0' + digits), n)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::testing::Chars;
+
+    /// A naive implementation of the natural order.
+    fn naive(x: &str, y: &str) -> Ordering {
+        fn split(s: &str) -> Vec<&str> {
+            let mut ret = Vec::new();
+            let mut rest = s;
+            while let Some(c) = rest.chars().next() {
+                let n = if c.is_ascii_digit() {
+                    rest.bytes().take_while(u8::is_ascii_digit).count()
+                } else {
+                    c.len_utf8()
+                };
+                ret.push(&rest[..n]);
+                rest = &rest[n..];
+            }
+            ret
+        }
+
+        let (xs, ys) = (split(x), split(y));
+        for (a, b) in xs.iter().zip(&ys) {
+            let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+            let ord = if is_digits(a) && is_digits(b) {
+                let (a_sig, b_sig) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+                a_sig
+                    .len()
+                    .cmp(&b_sig.len())
+                    .then_with(|| a_sig.cmp(b_sig))
+                    .then_with(|| a.len().cmp(&b.len()))
+            } else {
+                a.cmp(b)
+            };
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        }
+        xs.len().cmp(&ys.len())
+    }
+
+    #[test]
+    fn matches_naive() {
+        #[track_caller]
+        fn check(x: &str, y: &str, expected: Ordering) {
+            assert_eq!(naive(x, y), expected, "naive");
+            assert_eq!(cmp_natural(x, y), expected);
+            assert_eq!(cmp_natural(y, x), expected.reverse(), "rev");
+            assert_eq!(cmp_natural(&Chars(x), &Chars(y)), expected, "chars");
+            assert_eq!(cmp_natural(&Chars(x), y), expected, "chars,str");
+        }
+
+        use Ordering::{Equal, Greater, Less};
+
+        check("", "", Equal);
+        check("", "0", Less);
+        check("file1", "file1", Equal);
+        check("file2", "file10", Less);
+        check("file10", "file9", Greater);
+        check("file10a", "file10b", Less);
+        check("file10", "file10a", Less);
+        check("1", "a", Less);
+        check("a", "1", Greater);
+        check("a!", "a1", Less);
+        check("a:", "a1", Greater);
+        // Leading zeros.
+        check("a01", "a1", Greater);
+        check("a001", "a01", Greater);
+        check("a01", "a2", Less);
+        check("a0", "a00", Less);
+        check("a0", "a", Greater);
+        check("a010", "a9", Greater);
+        check("a01b", "a1c", Greater);
+        // Multiple runs.
+        check("1.2.10", "1.2.9", Greater);
+        check("1.10.1", "1.9.10", Greater);
+        check("x2y10", "x2y2", Greater);
+        check("é2", "é10", Less);
+    }
+
+    #[test]
+    fn long_runs() {
+        let long = |prefix: &str, digits: usize, last: char| {
+            let mut s = String::from(prefix);
+            s.extend((0..digits).map(|i| char::from(b'1' + (i % 9) as u8)));
+            s.push(last);
+            s
+        };
+
+        for &n in &[BUF - 2, BUF - 1, BUF, BUF + 1, BUF * 3] {
+            let (x, y) = (long("a", n, '1'), long("a", n, '2'));
+            let z = long("a", n + 1, '1');
+            let zeros = long("a0000", n, '1');
+            for &(x, y) in &[(&x, &y), (&x, &z), (&y, &z), (&x, &zeros), (&z, &zeros)] {
+                let expected = naive(x, y);
+                assert_eq!(cmp_natural(x.as_str(), y.as_str()), expected, "{}", n);
+                assert_eq!(cmp_natural(&Chars(x), &Chars(y)), expected, "{},chars", n);
+                let (x, y) = (x.clone() + "b", y.clone() + "a");
+                assert_eq!(cmp_natural(&Chars(&x), &Chars(&y)), naive(&x, &y), "{}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn sort() {
+        let mut files = ["file2", "file10", "file1", "file01", "file", "file1a"];
+        files.sort_by(cmp_natural);
+        assert_eq!(
+            files,
+            ["file", "file1", "file1a", "file01", "file2", "file10"]
+        );
+    }
+}
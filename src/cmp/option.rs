@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use super::{cmp, eq, hash};
+
+/// A wrapper type that compares an optional value, with the inner value compared in its `Display`
+/// representation.
+///
+/// `None` compares less than any `Some` value (including a `Some` value whose `Display`
+/// representation is empty), and `Some` values are compared with [`cmp`](super::cmp()) function
+/// like [`Cmp`](super::Cmp).
+///
+/// This implements `Display`, which renders `None` as an empty string and `Some(value)` as
+/// `value`. Note that this means that the `Display` representation does not distinguish `None`
+/// from `Some("")`, so `CmpOption` does not implement [`FmtEq`](crate::FmtEq) nor
+/// [`FmtOrd`](crate::FmtOrd).
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::CmpOption;
+///
+/// let mut values = vec![CmpOption(Some(240)), CmpOption(None), CmpOption(Some(42))];
+/// values.sort();
+/// assert_eq!(values, [CmpOption(None), CmpOption(Some(240)), CmpOption(Some(42))]);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CmpOption<T>(pub Option<T>);
+
+impl<T: Display> CmpOption<T> {
+    /// Wraps an `Option<T>` as a `CmpOption<T>`.
+    #[must_use]
+    pub fn new(value: Option<T>) -> Self {
+        CmpOption(value)
+    }
+
+    /// Unwraps the inner `Option<T>` of a `CmpOption<T>`.
+    #[must_use]
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> From<Option<T>> for CmpOption<T> {
+    fn from(value: Option<T>) -> Self {
+        CmpOption(value)
+    }
+}
+
+/// Renders `None` as an empty string.
+impl<T: Display> Display for CmpOption<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(ref value) => value.fmt(f),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Display, U: Display> PartialEq<CmpOption<U>> for CmpOption<T> {
+    fn eq(&self, other: &CmpOption<U>) -> bool {
+        match (&self.0, &other.0) {
+            (Some(lhs), Some(rhs)) => eq(lhs, rhs),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Display> Eq for CmpOption<T> {}
+
+impl<T: Display, U: Display> PartialOrd<CmpOption<U>> for CmpOption<T> {
+    fn partial_cmp(&self, other: &CmpOption<U>) -> Option<Ordering> {
+        Some(match (&self.0, &other.0) {
+            (Some(lhs), Some(rhs)) => cmp(lhs, rhs),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        })
+    }
+}
+
+impl<T: Display> Ord for CmpOption<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Some(lhs), Some(rhs)) => cmp(lhs, rhs),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+impl<T: Display> Hash for CmpOption<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash the discriminant first to distinguish `None` from `Some("")`.
+        match self.0 {
+            Some(ref value) => {
+                state.write_u8(1);
+                hash(value, state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::testing::hash_of;
+
+    #[test]
+    fn ordering() {
+        #[track_caller]
+        fn check<T: Display, U: Display>(x: Option<T>, y: Option<U>, expected: Ordering) {
+            let (x, y) = (CmpOption(x), CmpOption(y));
+            assert_eq!(x.partial_cmp(&y), Some(expected));
+            assert_eq!(y.partial_cmp(&x), Some(expected.reverse()), "rev");
+            assert_eq!(x == y, expected == Ordering::Equal);
+        }
+
+        check::<&str, &str>(None, None, Ordering::Equal);
+        check(None::<&str>, Some(""), Ordering::Less);
+        check(None::<u32>, Some(42), Ordering::Less);
+        check(Some(""), Some(""), Ordering::Equal);
+        check(Some(42), Some(240), Ordering::Greater);
+        check(Some(42), Some("42"), Ordering::Equal);
+        check(Some("abc"), Some("abd"), Ordering::Less);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(CmpOption::<u32>(None).to_string(), "");
+        assert_eq!(CmpOption(Some(42)).to_string(), "42");
+    }
+
+    #[test]
+    fn hash_distinguishes_none() {
+        assert_eq!(
+            hash_of(&CmpOption::<&str>(None)),
+            hash_of(&CmpOption::<&str>(None))
+        );
+        assert_ne!(
+            hash_of(&CmpOption::<&str>(None)),
+            hash_of(&CmpOption(Some("")))
+        );
+        assert_eq!(
+            hash_of(&CmpOption(Some("42"))),
+            hash_of(&CmpOption(Some(format_args!("{}", 42))))
+        );
+    }
+}
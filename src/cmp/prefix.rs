@@ -0,0 +1,166 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+
+use super::cmp;
+
+/// Compares two values in the first `max_bytes` bytes of their `Display` representations.
+///
+/// Each representation is truncated to at most `max_bytes` bytes, rounding down to a `char`
+/// boundary so that no UTF-8 sequence is split. This yields the same result as comparing
+/// `&s[..n]` for the `to_string()` outputs `s` where `n` is the largest char boundary not
+/// greater than `max_bytes`, without heap allocation. In particular, two values whose
+/// representations agree in the first `max_bytes` bytes compare equal.
+///
+/// The formatting stops once the budget is exhausted by returning an error from the writer, so
+/// the rest of each representation is never formatted.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations. In
+/// addition, the `Display` implementations must propagate the errors from the writer, which they
+/// are supposed to do anyway.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::cmp_prefix;
+///
+/// assert!(cmp_prefix("foobar", "foobaz", 5).is_eq());
+/// assert!(cmp_prefix("foobar", "foobaz", 6).is_lt());
+/// // `"4" < "5"`
+/// assert!(cmp_prefix(&42, &5, 1).is_lt());
+/// ```
+#[must_use]
+pub fn cmp_prefix<T: Display + ?Sized, U: Display + ?Sized>(
+    lhs: &T,
+    rhs: &U,
+    max_bytes: usize,
+) -> Ordering {
+    cmp(&Prefix(lhs, max_bytes), &Prefix(rhs, max_bytes))
+}
+
+/// A `Display` adapter that truncates the representation to at most the given number of bytes.
+struct Prefix<'a, T: ?Sized>(&'a T, usize);
+
+impl<T: Display + ?Sized> Display for Prefix<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        struct Adapter<'a, 'b> {
+            inner: &'a mut Formatter<'b>,
+            remaining: usize,
+            exhausted: bool,
+        }
+
+        impl Write for Adapter<'_, '_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                if s.len() < self.remaining {
+                    self.remaining -= s.len();
+                    return self.inner.write_str(s);
+                }
+
+                let mut end = self.remaining;
+                while !s.is_char_boundary(end) {
+                    end -= 1;
+                }
+                self.inner.write_str(&s[..end])?;
+                self.remaining = 0;
+                self.exhausted = true;
+                // Stop formatting the rest of the value.
+                Err(fmt::Error)
+            }
+        }
+
+        let mut adapter = Adapter {
+            inner: f,
+            remaining: self.1,
+            exhausted: false,
+        };
+        match write!(adapter, "{}", self.0) {
+            Err(_) if adapter.exhausted => Ok(()),
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::testing::Chars;
+
+    fn truncate(s: &str, max_bytes: usize) -> &str {
+        let mut end = max_bytes.min(s.len());
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        &s[..end]
+    }
+
+    #[test]
+    fn matches_truncated_cmp() {
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            for max_bytes in 0..=x.len().max(y.len()) + 1 {
+                let expected = truncate(x, max_bytes).cmp(truncate(y, max_bytes));
+                assert_eq!(cmp_prefix(x, y, max_bytes), expected, "{}", max_bytes);
+                assert_eq!(
+                    cmp_prefix(y, x, max_bytes),
+                    expected.reverse(),
+                    "{},rev",
+                    max_bytes
+                );
+                assert_eq!(
+                    cmp_prefix(&Chars(x), &Chars(y), max_bytes),
+                    expected,
+                    "{},chars",
+                    max_bytes
+                );
+                assert_eq!(
+                    Prefix(&Chars(x), max_bytes).to_string(),
+                    truncate(x, max_bytes)
+                );
+            }
+        }
+
+        check("", "");
+        check("foo", "foo");
+        check("foobar", "foobaz");
+        check("foo", "foobar");
+        check("abc", "abd");
+        check("aé", "aè");
+        check("é", "e\u{301}");
+        check("\u{10000}a", "\u{10000}b");
+    }
+
+    #[test]
+    fn equal_prefixes() {
+        assert_eq!(cmp_prefix("foobar", "foobaz", 5), Ordering::Equal);
+        assert_eq!(cmp_prefix("foobar", "foobaz", 6), Ordering::Less);
+        assert_eq!(cmp_prefix(&12_345, &12_399, 3), Ordering::Equal);
+        assert_eq!(cmp_prefix(&12_345, &12_399, 4), Ordering::Less);
+        // `'é'` doesn't fit in 2 bytes, so only `"a"` is compared.
+        assert_eq!(cmp_prefix("aé", "aè", 2), Ordering::Equal);
+        assert_eq!(cmp_prefix("aé", "aè", 3), Ordering::Greater);
+    }
+
+    #[test]
+    fn stops_early() {
+        /// Panics if formatted past the first chunk.
+        struct Bomb;
+
+        impl Display for Bomb {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("foo")?;
+                f.write_str("bar")?;
+                panic!("formatted past the budget");
+            }
+        }
+
+        assert_eq!(cmp_prefix(&Bomb, "fooba", 5), Ordering::Equal);
+        assert_eq!(cmp_prefix(&Bomb, "foobar", 6), Ordering::Equal);
+        assert_eq!(cmp_prefix(&Bomb, &Bomb, 2), Ordering::Equal);
+    }
+}
@@ -0,0 +1,366 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use super::{cmp, eq, hash};
+use crate::{FmtEq, FmtOrd};
+
+/// A wrapper type that compares a slice of values in the `Display` representation of the values
+/// joined by a separator.
+///
+/// Slices and arrays do not implement `Display`, so they cannot be wrapped in
+/// [`Cmp`](super::Cmp) directly. `CmpSeq(items, separator)` implements `Display` by writing each
+/// item with `separator` between them, and compares with the joined representation without heap
+/// allocation, i.e., the comparison yields the same result as comparing
+/// `items.iter().map(ToString::to_string).collect::<Vec<_>>().join(separator)`.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::CmpSeq;
+///
+/// assert_eq!(CmpSeq(&[1, 2, 3], "."), CmpSeq(&["1.2", "3"], "."));
+/// // `"a,bb" < "ab,b"`.
+/// assert!(CmpSeq(&["a", "bb"], ",") < CmpSeq(&["ab", "b"], ","));
+/// ```
+#[derive(Debug)]
+pub struct CmpSeq<'a, T>(pub &'a [T], pub &'a str);
+
+impl<T> Clone for CmpSeq<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CmpSeq<'_, T> {}
+
+impl<T: Display> Display for CmpSeq<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut items = self.0.iter();
+        if let Some(first) = items.next() {
+            first.fmt(f)?;
+            for item in items {
+                f.write_str(self.1)?;
+                item.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display, U: Display> PartialEq<CmpSeq<'_, U>> for CmpSeq<'_, T> {
+    fn eq(&self, other: &CmpSeq<'_, U>) -> bool {
+        eq(self, other)
+    }
+}
+
+impl<T: Display> Eq for CmpSeq<'_, T> {}
+
+impl<T: Display, U: Display> PartialOrd<CmpSeq<'_, U>> for CmpSeq<'_, T> {
+    fn partial_cmp(&self, other: &CmpSeq<'_, U>) -> Option<Ordering> {
+        Some(cmp(self, other))
+    }
+}
+
+impl<T: Display> Ord for CmpSeq<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp(self, other)
+    }
+}
+
+impl<T: Display> Hash for CmpSeq<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash(self, state)
+    }
+}
+
+impl<T: Display> FmtEq for CmpSeq<'_, T> {}
+impl<T: Display> FmtOrd for CmpSeq<'_, T> {}
+
+/// Compares two sequences of values in the `Display` representations of the values joined by a
+/// separator.
+///
+/// This is like comparing [`CmpSeq`]s, but takes iterators instead of slices, so that the items
+/// can be generated on the fly, e.g., by `str::split` or `Path::components`. The comparison yields
+/// the same result as comparing `lhs.into_iter().map(|x| x.to_string()).collect::<Vec<_>>()
+/// .join(sep)` with the same for `rhs`, without heap allocation.
+///
+/// ## Note
+///
+/// Since the comparison may format each value multiple times, the iterators must implement
+/// `Clone` and each formatting iterates over a clone of them, so they should be cheap to clone
+/// and yield the same items every time.
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::cmp_joined;
+///
+/// // `"usr/lib" == "usr/lib"`
+/// assert!(cmp_joined("usr/lib".split('/'), ["usr", "lib"].iter(), "/").is_eq());
+/// // `"a/b" > "a-b"` since `'/' > '-'`.
+/// assert!(cmp_joined(["a", "b"].iter(), ["a-b"].iter(), "/").is_gt());
+/// // `"1,10" < "1,9"`
+/// assert!(cmp_joined([1, 10].iter(), [1, 9].iter(), ",").is_lt());
+/// ```
+#[must_use]
+pub fn cmp_joined<T, U, I, J>(lhs: I, rhs: J, sep: &str) -> Ordering
+where
+    T: Display,
+    U: Display,
+    I: IntoIterator<Item = T>,
+    I::IntoIter: Clone,
+    J: IntoIterator<Item = U>,
+    J::IntoIter: Clone,
+{
+    cmp(&Joined(lhs.into_iter(), sep), &Joined(rhs.into_iter(), sep))
+}
+
+/// Tests two sequences of values for equality in the `Display` representations of the values
+/// joined by a separator.
+///
+/// See [`cmp_joined`] for the details.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::eq_joined;
+///
+/// assert!(eq_joined("a,b,c".split(','), ["a,b", "c"].iter(), ","));
+/// assert!(!eq_joined("a,b,c".split(','), ["a,b", "c"].iter(), ";"));
+/// ```
+#[must_use]
+pub fn eq_joined<T, U, I, J>(lhs: I, rhs: J, sep: &str) -> bool
+where
+    T: Display,
+    U: Display,
+    I: IntoIterator<Item = T>,
+    I::IntoIter: Clone,
+    J: IntoIterator<Item = U>,
+    J::IntoIter: Clone,
+{
+    eq(&Joined(lhs.into_iter(), sep), &Joined(rhs.into_iter(), sep))
+}
+
+/// A `Display` adapter that writes the items of (a clone of) an iterator joined by a separator.
+struct Joined<'a, I>(I, &'a str);
+
+impl<I: Iterator + Clone> Display for Joined<'_, I>
+where
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut items = self.0.clone();
+        if let Some(first) = items.next() {
+            first.fmt(f)?;
+            for item in items {
+                f.write_str(self.1)?;
+                item.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compares two pairs of values in the concatenations of their `Display` representations.
+///
+/// This yields the same result as `format!("{}{}", lhs.0, lhs.1).cmp(&format!("{}{}", rhs.0,
+/// rhs.1))` without heap allocation. Note that this is not the same as comparing the first
+/// elements and then the second ones, since a longer first element is compared with a part of the
+/// second element of the other pair.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::cmp_tuple2;
+///
+/// // `"ab" . "c" == "a" . "bc"`
+/// assert!(cmp_tuple2(("ab", "c"), ("a", "bc")).is_eq());
+/// // `"abc" < "abd"` although `"ab" > "a"`.
+/// assert!(cmp_tuple2(("ab", "c"), ("a", "bd")).is_lt());
+/// // `"42" > "240"`
+/// assert!(cmp_tuple2((&4, &2), (&24, &0)).is_gt());
+/// ```
+#[must_use]
+pub fn cmp_tuple2<A: Display + ?Sized, B: Display + ?Sized>(
+    lhs: (&A, &B),
+    rhs: (&A, &B),
+) -> Ordering {
+    cmp(&Concat2(lhs), &Concat2(rhs))
+}
+
+/// Compares two triples of values in the concatenations of their `Display` representations.
+///
+/// This yields the same result as `format!("{}{}{}", lhs.0, lhs.1, lhs.2).cmp(&format!("{}{}{}",
+/// rhs.0, rhs.1, rhs.2))` without heap allocation. See [`cmp_tuple2`] for the details.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::cmp_tuple3;
+///
+/// assert!(cmp_tuple3(("a", "b", "c"), ("", "ab", "c")).is_eq());
+/// // `"1.2.10" < "1.2.9"`
+/// assert!(cmp_tuple3((&1, ".2.", &10), (&1, ".2.", &9)).is_lt());
+/// ```
+#[must_use]
+pub fn cmp_tuple3<A: Display + ?Sized, B: Display + ?Sized, C: Display + ?Sized>(
+    lhs: (&A, &B, &C),
+    rhs: (&A, &B, &C),
+) -> Ordering {
+    cmp(&Concat3(lhs), &Concat3(rhs))
+}
+
+/// A `Display` adapter that writes the elements of a pair one after another.
+struct Concat2<'a, A: ?Sized, B: ?Sized>((&'a A, &'a B));
+
+impl<A: Display + ?Sized, B: Display + ?Sized> Display for Concat2<'_, A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.0).0.fmt(f)?;
+        (self.0).1.fmt(f)
+    }
+}
+
+/// A `Display` adapter that writes the elements of a triple one after another.
+struct Concat3<'a, A: ?Sized, B: ?Sized, C: ?Sized>((&'a A, &'a B, &'a C));
+
+impl<A: Display + ?Sized, B: Display + ?Sized, C: Display + ?Sized> Display
+    for Concat3<'_, A, B, C>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.0).0.fmt(f)?;
+        (self.0).1.fmt(f)?;
+        (self.0).2.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn join<T: Display>(items: &[T], separator: &str) -> String {
+        items
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    #[test]
+    fn matches_joined_cmp() {
+        #[track_caller]
+        fn check<T: Display, U: Display>(x: &[T], y: &[U], separator: &str) {
+            let expected = join(x, separator).cmp(&join(y, separator));
+            let (x, y) = (CmpSeq(x, separator), CmpSeq(y, separator));
+            assert_eq!(x.partial_cmp(&y), Some(expected));
+            assert_eq!(y.partial_cmp(&x), Some(expected.reverse()), "rev");
+            assert_eq!(x == y, expected == Ordering::Equal);
+        }
+
+        check(&["a", "bb"], &["ab", "b"], ",");
+        check(&["a", "bb"], &["a,bb"], ",");
+        check(&["a", "bb"], &["a", "bb"], "");
+        check(&["a", "bb"], &["ab", "b"], "");
+        check(&[1, 2, 3], &["1.2", "3"], ".");
+        check(&[42], &[240], ", ");
+        check::<&str, &str>(&[], &[], ",");
+        check::<&str, &str>(&[], &[""], ",");
+        check::<&str, &str>(&[""], &["", ""], ",");
+        check(&["", ""], &[","], ",");
+    }
+
+    #[test]
+    fn joined_matches_joined_cmp() {
+        #[track_caller]
+        fn check<T: Display, U: Display>(x: &[T], y: &[U], sep: &str) {
+            let expected = join(x, sep).cmp(&join(y, sep));
+            assert_eq!(cmp_joined(x, y, sep), expected);
+            assert_eq!(cmp_joined(y, x, sep), expected.reverse(), "rev");
+            assert_eq!(eq_joined(x, y, sep), expected == Ordering::Equal, "eq");
+            assert_eq!(
+                cmp_joined(x, y, sep),
+                CmpSeq(x, sep).partial_cmp(&CmpSeq(y, sep)).unwrap(),
+                "CmpSeq"
+            );
+        }
+
+        // The same representation from different splits.
+        check(&["a", "b"], &["a/b"], "/");
+        // The separator compares with the item bytes at the same position: `'/' > '-'`.
+        check(&["a", "b"], &["a-b"], "/");
+        check(&["a", "b"], &["a", "b"], "");
+        check(&["a", "b"], &["ab"], "");
+        check(&["a", "bb"], &["ab", "b"], ",");
+        check(&[1, 10], &[1, 9], ",");
+        check(&[1, 2, 3], &["1.2", "3"], ".");
+        check::<&str, &str>(&[], &[], ",");
+        check::<&str, &str>(&[], &[""], ",");
+        check::<&str, &str>(&[""], &["", ""], ",");
+        check(&["", ""], &[","], ",");
+
+        // Lazily generated items.
+        assert!(eq_joined("a/b/c".split('/'), "a/b/c".split('/'), "::"));
+        assert!(eq_joined("a/b/c".split('/'), ["a::b", "c"].iter(), "::"));
+        assert_eq!(
+            cmp_joined((1..=10).map(|i| i * i), (1..=3).rev(), "-"),
+            "1-4-9-16-25-36-49-64-81-100".cmp("3-2-1")
+        );
+    }
+
+    #[test]
+    fn tuples_match_concatenated_cmp() {
+        use alloc::format;
+
+        #[track_caller]
+        fn check2<A: Display, B: Display>(x: (A, B), y: (A, B)) {
+            let expected = format!("{}{}", x.0, x.1).cmp(&format!("{}{}", y.0, y.1));
+            assert_eq!(cmp_tuple2((&x.0, &x.1), (&y.0, &y.1)), expected);
+            assert_eq!(
+                cmp_tuple2((&y.0, &y.1), (&x.0, &x.1)),
+                expected.reverse(),
+                "rev"
+            );
+        }
+
+        #[track_caller]
+        fn check3<A: Display, B: Display, C: Display>(x: (A, B, C), y: (A, B, C)) {
+            let expected = format!("{}{}{}", x.0, x.1, x.2).cmp(&format!("{}{}{}", y.0, y.1, y.2));
+            assert_eq!(cmp_tuple3((&x.0, &x.1, &x.2), (&y.0, &y.1, &y.2)), expected);
+            assert_eq!(
+                cmp_tuple3((&y.0, &y.1, &y.2), (&x.0, &x.1, &x.2)),
+                expected.reverse(),
+                "rev"
+            );
+        }
+
+        check2(("ab", "c"), ("a", "bc"));
+        check2(("a", "c"), ("ab", ""));
+        check2(("", ""), ("", ""));
+        check2(("", "a"), ("a", ""));
+        check2((4, 2), (24, 0));
+        check2((42, 0), (4, 20));
+        check2((1, "z"), (10, "a"));
+        check2((1.5, 'x'), (1.25, 'y'));
+
+        check3(("a", "b", "c"), ("", "ab", "c"));
+        check3(("a", "b", "c"), ("a", "b", "d"));
+        check3((1, ".2.", 10), (1, ".2.", 9));
+        check3((12, 3, 4), (1, 23, 5));
+        check3(("", "", ""), ("", "", "a"));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(CmpSeq(&["a", "bb"], ",").to_string(), "a,bb");
+        assert_eq!(CmpSeq::<u32>(&[], ",").to_string(), "");
+        assert_eq!(CmpSeq(&[1, 2, 3], ", ").to_string(), "1, 2, 3");
+    }
+}
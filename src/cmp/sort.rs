@@ -0,0 +1,319 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use super::cmp;
+
+/// Sorts a slice lexicographically by the `Display` representations of its elements.
+///
+/// This is equivalent to `slice.sort_unstable_by(|a, b| fmt_cmp::cmp(a, b))` and does not allocate
+/// on the heap memory. As the name of the equivalent method suggests, the sort is not stable, i.e.,
+/// it may reorder elements with the same `Display` representations. Use `sorted` (with `alloc`
+/// feature) if you need a stable sort.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// let mut values: Vec<u32> = (1..=10).collect();
+/// fmt_cmp::sort_slice(&mut values);
+/// assert_eq!(values, [1, 10, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+pub fn sort_slice<T: Display>(slice: &mut [T]) {
+    slice.sort_unstable_by(cmp);
+}
+
+/// Collects an iterator into a `Vec` sorted lexicographically by the `Display` representations of
+/// its elements.
+///
+/// This is equivalent to collecting the iterator into a `Vec` and then calling
+/// `vec.sort_by(|a, b| fmt_cmp::cmp(a, b))` on it. Unlike [`sort_slice`], the sort is stable.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// assert_eq!(fmt_cmp::sorted(1..=10), [1, 10, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn sorted<I>(iter: I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    let mut vec: Vec<I::Item> = iter.into_iter().collect();
+    vec.sort_by(cmp);
+    vec
+}
+
+/// Returns [`cmp`](super::cmp()) function as a function pointer comparing values of type `T`.
+///
+/// This is handy for passing to sorting methods like `slice::sort_by` without writing a closure,
+/// and the returned function pointer type is nameable, e.g., in a struct field.
+///
+/// ## Example
+///
+/// ```
+/// let mut values = vec![3, 10, 2, 1];
+/// values.sort_by(fmt_cmp::by_display());
+/// assert_eq!(values, [1, 10, 2, 3]);
+///
+/// struct Sorter<T> {
+///     compare: fn(&T, &T) -> std::cmp::Ordering,
+/// }
+///
+/// let sorter = Sorter { compare: fmt_cmp::by_display::<u32>() };
+/// assert!((sorter.compare)(&42, &240).is_gt());
+/// ```
+#[must_use]
+pub fn by_display<T: Display + ?Sized>() -> fn(&T, &T) -> Ordering {
+    cmp::<T, T>
+}
+
+/// Returns a function pointer that renders a value of type `T` into a `String`, whose ordering is
+/// the same as comparing the values with [`cmp`](super::cmp()).
+///
+/// This is meant to be passed to `slice::sort_by_cached_key`, which formats each element only once
+/// at the cost of allocating the `String`s, unlike [`by_display`].
+///
+/// ## Example
+///
+/// ```
+/// let mut values = vec![3, 10, 2, 1];
+/// values.sort_by_cached_key(fmt_cmp::by_display_key());
+/// assert_eq!(values, [1, 10, 2, 3]);
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn by_display_key<T: Display + ?Sized>() -> fn(&T) -> String {
+    <T as ToString>::to_string
+}
+
+/// Inserts a value into a `Vec` sorted lexicographically by the `Display` representations of its
+/// elements, keeping it sorted, and returns the index of the inserted value.
+///
+/// This is equivalent to `vec.binary_search_by(|x| fmt_cmp::cmp(x, &value))` followed by
+/// `vec.insert(pos, value)`, except that the value is always inserted after the existing elements
+/// with the same `Display` representation. So inserting values one by one yields the same order as
+/// the stable sort of [`sorted`].
+///
+/// The insertion position is unspecified if `vec` is not sorted.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// let mut values = Vec::new();
+/// for i in 1..=10 {
+///     fmt_cmp::insert_sorted_by_display(&mut values, i);
+/// }
+/// assert_eq!(values, [1, 10, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// assert_eq!(fmt_cmp::insert_sorted_by_display(&mut values, 11), 2);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn insert_sorted_by_display<T: Display>(vec: &mut Vec<T>, value: T) -> usize {
+    // Never reporting `Equal` makes the search end up after the elements equal to `value`.
+    let pos = match vec.binary_search_by(|x| match cmp(x, &value) {
+        Ordering::Equal => Ordering::Less,
+        ord => ord,
+    }) {
+        Ok(pos) | Err(pos) => pos,
+    };
+    vec.insert(pos, value);
+    pos
+}
+
+/// Sorts an `IndexSet` lexicographically by the `Display` representations of its elements.
+///
+/// `IndexSet::sort` sorts the elements by their `Ord` implementations, which may not exist or may
+/// disagree with the `Display` representations. This sorts the set with [`cmp`](super::cmp())
+/// instead, i.e., it is equivalent to `set.sort_by(|a, b| fmt_cmp::cmp(a, b))`. The sort is
+/// stable.
+///
+/// This function is available with `indexmap` feature.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use indexmap::IndexSet;
+///
+/// let mut set: IndexSet<i32> = (-2..=10).collect();
+/// fmt_cmp::cmp::sort_index_set_by_display(&mut set);
+/// assert!(set.iter().eq(&[-1, -2, 0, 1, 10, 2, 3, 4, 5, 6, 7, 8, 9]));
+/// ```
+#[cfg(feature = "indexmap")]
+pub fn sort_index_set_by_display<T: Display, S>(set: &mut indexmap::IndexSet<T, S>) {
+    set.sort_by(cmp);
+}
+
+/// Sorts an `IndexMap` lexicographically by the `Display` representations of its keys.
+///
+/// This is the `IndexMap` counterpart of [`sort_index_set_by_display`], i.e., it is equivalent to
+/// `map.sort_by(|k1, _, k2, _| fmt_cmp::cmp(k1, k2))`. The sort is stable.
+///
+/// This function is available with `indexmap` feature.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// use indexmap::IndexMap;
+///
+/// let mut map: IndexMap<u32, &str> = vec![(2, "a"), (10, "b")].into_iter().collect();
+/// fmt_cmp::cmp::sort_index_map_keys_by_display(&mut map);
+/// assert!(map.values().eq(&["b", "a"]));
+/// ```
+#[cfg(feature = "indexmap")]
+pub fn sort_index_map_keys_by_display<K: Display, V, S>(map: &mut indexmap::IndexMap<K, V, S>) {
+    map.sort_by(|k1, _, k2, _| cmp(k1, k2));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_slice_lexicographically() {
+        let mut values = [1_u32, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        sort_slice(&mut values);
+        assert_eq!(values, [1, 10, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let mut values = [-1_i32, 0, -10, 1, 10];
+        sort_slice(&mut values);
+        assert_eq!(values, [-1, -10, 0, 1, 10]);
+    }
+
+    #[test]
+    fn by_display_is_cmp() {
+        let mut values = [-1_i32, 0, -10, 1, 10];
+        values.sort_by(by_display());
+        assert_eq!(values, [-1, -10, 0, 1, 10]);
+
+        let compare: fn(&str, &str) -> Ordering = by_display();
+        assert_eq!(compare("42", "240"), Ordering::Greater);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn by_display_key_matches_by_display() {
+        let mut values = [-1_i32, 0, -10, 1, 10, 100, -100];
+        let mut expected = values;
+        expected.sort_by(by_display());
+        values.sort_by_cached_key(by_display_key());
+        assert_eq!(values, expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sorted_is_stable() {
+        use alloc::vec;
+
+        use crate::testing::Key;
+
+        assert_eq!(sorted(1..=10), [1, 10, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let values = vec![Key(2, 0), Key(10, 1), Key(2, 2), Key(1, 3), Key(10, 4)];
+        assert_eq!(
+            sorted(values),
+            [Key(1, 3), Key(10, 1), Key(10, 4), Key(2, 0), Key(2, 2)]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn insert_sorted_by_display_matches_btree_set() {
+        use alloc::collections::BTreeSet;
+        use alloc::vec::Vec;
+
+        use crate::testing::Key;
+        use crate::Cmp;
+
+        let mut values = Vec::new();
+        for i in 1..=10 {
+            insert_sorted_by_display(&mut values, i);
+        }
+        let set: BTreeSet<Cmp<u32>> = (1..=10).map(Cmp).collect();
+        assert!(values.iter().copied().map(Cmp).eq(set));
+        assert_eq!(values, [1, 10, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        // Equal values are inserted after the existing ones, like a stable sort.
+        let mut values = Vec::new();
+        let inserted: Vec<usize> = [2, 10, 2, 1, 10]
+            .iter()
+            .zip(0..)
+            .map(|(&k, i)| insert_sorted_by_display(&mut values, Key(k, i)))
+            .collect();
+        assert_eq!(inserted, [0, 0, 2, 0, 2]);
+        assert_eq!(
+            values,
+            [Key(1, 3), Key(10, 1), Key(10, 4), Key(2, 0), Key(2, 2)]
+        );
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn sort_index_set_by_display_lexicographically() {
+        use indexmap::{IndexMap, IndexSet};
+        use std::fmt::{self, Formatter};
+        use std::hash::{Hash, Hasher};
+
+        /// An `f64` that is `Eq` and `Hash` (by the bits) but not `Ord`.
+        #[derive(Clone, Copy, Debug)]
+        struct F64(f64);
+
+        impl PartialEq for F64 {
+            fn eq(&self, other: &Self) -> bool {
+                self.0.to_bits() == other.0.to_bits()
+            }
+        }
+
+        impl Eq for F64 {}
+
+        impl Hash for F64 {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl Display for F64 {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        let values = [10.0_f64, 2.5, -0.5, 1e21, 100.0, 1.0, -10.0, 0.0];
+        let mut set: IndexSet<F64> = values.iter().copied().map(F64).collect();
+        sort_index_set_by_display(&mut set);
+        assert!(set
+            .iter()
+            .map(|x| x.0)
+            .eq([-0.5, -10.0, 0.0, 1.0, 10.0, 100.0, 1e21, 2.5]
+                .iter()
+                .copied()));
+
+        let mut map: IndexMap<F64, usize> = values.iter().copied().map(F64).zip(0..).collect();
+        sort_index_map_keys_by_display(&mut map);
+        assert!(map.keys().eq(&set));
+        assert!(map.values().eq(&[2, 6, 7, 5, 0, 4, 3, 1]));
+    }
+}
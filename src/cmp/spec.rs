@@ -163,4 +163,7 @@ macro_rules! int_ord {
     )*};
 }
 
-int_ord! { u8 u16 u32 u64 usize u128 }
+int_ord! {
+    u8 u16 u32 u64 usize u128
+    i8 i16 i32 i64 isize i128
+}
@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use super::generic::mismatch;
+
+/// An incremental comparator of two strings given as streams of chunks.
+///
+/// This yields the same result as concatenating the chunks of each side and comparing the
+/// resulting strings, but only buffers the part of the side that is ahead of the other side, and
+/// stops buffering as soon as the result is determined.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::StreamCmp;
+///
+/// let mut cmp = StreamCmp::new();
+/// cmp.push_lhs("abra");
+/// cmp.push_lhs("cadabra");
+/// cmp.push_rhs("ab");
+/// cmp.push_rhs("rabanana");
+/// assert!(cmp.finish().is_gt());
+/// ```
+#[derive(Clone, Debug)]
+pub struct StreamCmp {
+    ret: Ordering,
+    /// Bytes of one side that are not yet compared with the other side.
+    pending: Vec<u8>,
+    /// Whether `pending` belongs to the left hand side.
+    pending_is_lhs: bool,
+}
+
+impl StreamCmp {
+    /// Creates a new `StreamCmp` with both sides empty.
+    #[must_use]
+    pub fn new() -> Self {
+        StreamCmp {
+            ret: Ordering::Equal,
+            pending: Vec::new(),
+            pending_is_lhs: false,
+        }
+    }
+
+    /// Appends a chunk to the left hand side.
+    pub fn push_lhs(&mut self, chunk: &str) {
+        self.push(chunk.as_bytes(), true);
+    }
+
+    /// Appends a chunk to the right hand side.
+    pub fn push_rhs(&mut self, chunk: &str) {
+        self.push(chunk.as_bytes(), false);
+    }
+
+    /// Returns whether the result is already determined regardless of the upcoming chunks.
+    #[must_use]
+    pub fn is_determined(&self) -> bool {
+        self.ret != Ordering::Equal
+    }
+
+    /// Returns the ordering between the chunks pushed so far to each side.
+    #[must_use]
+    pub fn finish(&self) -> Ordering {
+        if self.ret != Ordering::Equal || self.pending.is_empty() {
+            self.ret
+        } else if self.pending_is_lhs {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+
+    fn push(&mut self, chunk: &[u8], is_lhs: bool) {
+        if self.ret != Ordering::Equal {
+            return;
+        }
+
+        if self.pending.is_empty() || self.pending_is_lhs == is_lhs {
+            // This side is ahead of (or even with) the other side.
+            self.pending.extend_from_slice(chunk);
+            self.pending_is_lhs = is_lhs;
+            return;
+        }
+
+        let read = chunk.len().min(self.pending.len());
+        if let Some(i) = mismatch(&self.pending[..read], &chunk[..read]) {
+            let ret = self.pending[i].cmp(&chunk[i]);
+            self.ret = if self.pending_is_lhs {
+                ret
+            } else {
+                ret.reverse()
+            };
+            self.pending = Vec::new();
+            return;
+        }
+
+        self.pending.drain(..read);
+        if read < chunk.len() {
+            // This side has overtaken the other side.
+            self.pending.extend_from_slice(&chunk[read..]);
+            self.pending_is_lhs = is_lhs;
+        }
+    }
+}
+
+impl Default for StreamCmp {
+    fn default() -> Self {
+        StreamCmp::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_str_cmp() {
+        fn split(s: &str, n: usize) -> impl Iterator<Item = &str> {
+            let mut pos = 0;
+            s.split_inclusive(move |_| {
+                let ret = n == 0 || (pos != 0 && pos % n == 0);
+                pos += 1;
+                ret
+            })
+        }
+
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let expected = x.cmp(y);
+            for (nx, ny) in (0..=x.len()).flat_map(|i| (0..=y.len()).map(move |j| (i, j))) {
+                // Push all the chunks of `x` first.
+                let mut cmp = StreamCmp::new();
+                split(x, nx).for_each(|s| cmp.push_lhs(s));
+                split(y, ny).for_each(|s| cmp.push_rhs(s));
+                assert_eq!(cmp.finish(), expected, "{:?}", (nx, ny));
+
+                // Push all the chunks of `y` first.
+                let mut cmp = StreamCmp::new();
+                split(y, ny).for_each(|s| cmp.push_rhs(s));
+                split(x, nx).for_each(|s| cmp.push_lhs(s));
+                assert_eq!(cmp.finish(), expected, "{:?},rhs-first", (nx, ny));
+
+                // Interleave the chunks.
+                let mut cmp = StreamCmp::new();
+                let (mut xs, mut ys) = (split(x, nx), split(y, ny));
+                loop {
+                    let (xc, yc) = (xs.next(), ys.next());
+                    if xc.is_none() && yc.is_none() {
+                        break;
+                    }
+                    if let Some(s) = xc {
+                        cmp.push_lhs(s);
+                    }
+                    if let Some(s) = yc {
+                        cmp.push_rhs(s);
+                    }
+                }
+                assert_eq!(cmp.finish(), expected, "{:?},interleaved", (nx, ny));
+            }
+        }
+
+        check("", "");
+        check("", "42");
+        check("abracadabra", "abracadabra");
+        check("abracadabra", "abracad");
+        check("abracadabra", "abrabanana");
+        check("42", "240");
+        check("日本語", "日本人");
+    }
+
+    #[test]
+    fn is_determined() {
+        let mut cmp = StreamCmp::new();
+        cmp.push_lhs("abra");
+        cmp.push_rhs("abra");
+        assert!(!cmp.is_determined());
+        cmp.push_rhs("b");
+        assert!(!cmp.is_determined());
+        cmp.push_lhs("c");
+        assert!(cmp.is_determined());
+        cmp.push_rhs("cadabra");
+        assert!(cmp.finish().is_gt());
+    }
+}
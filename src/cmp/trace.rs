@@ -0,0 +1,159 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+
+use super::generic;
+
+/// Statistics of a comparison returned by [`cmp_traced`].
+///
+/// This is available with `trace` feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CmpStats {
+    /// The total number of bytes written by the `Display` implementation of the left operand.
+    pub lhs_bytes: usize,
+    /// The total number of bytes written by the `Display` implementation of the right operand.
+    ///
+    /// The right operand is formatted once for each chunk written by the left operand, so this
+    /// may exceed the length of its representation by far. If it does, caching the
+    /// representation with `CmpKey` or comparing with `cmp_buffered` (both with `alloc` feature)
+    /// may be faster.
+    pub rhs_bytes: usize,
+    /// Whether the comparison stopped before formatting either representation to the end.
+    pub short_circuited: bool,
+}
+
+/// Compares two values in their `Display` representations like [`cmp`](super::cmp()) does, and
+/// reports how much work the comparison took.
+///
+/// This always takes the generic (formatting) implementation of `cmp`, even for the types that
+/// `cmp` may compare without formatting.
+///
+/// This is available with `trace` feature.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::cmp_traced;
+///
+/// let (ret, stats) = cmp_traced("zzz", "aaa");
+/// assert!(ret.is_gt());
+/// assert!(stats.short_circuited);
+///
+/// let (ret, stats) = cmp_traced("abc", "abc");
+/// assert!(ret.is_eq());
+/// assert_eq!((stats.lhs_bytes, stats.rhs_bytes), (3, 3));
+/// assert!(!stats.short_circuited);
+/// ```
+#[must_use]
+pub fn cmp_traced<T: Display + ?Sized, U: Display + ?Sized>(
+    lhs: &T,
+    rhs: &U,
+) -> (Ordering, CmpStats) {
+    let (lhs, rhs) = (Traced::new(lhs), Traced::new(rhs));
+    let ret = generic::cmp(&lhs, &rhs);
+    let stats = CmpStats {
+        lhs_bytes: lhs.bytes.get(),
+        rhs_bytes: rhs.bytes.get(),
+        short_circuited: lhs.aborted.get() || rhs.aborted.get(),
+    };
+    (ret, stats)
+}
+
+/// A `Display` adapter that records the number of bytes written by the inner value.
+struct Traced<'a, T: ?Sized> {
+    value: &'a T,
+    bytes: Cell<usize>,
+    /// Whether the last formatting was stopped by the writer.
+    aborted: Cell<bool>,
+}
+
+impl<'a, T: Display + ?Sized> Traced<'a, T> {
+    fn new(value: &'a T) -> Self {
+        Traced {
+            value,
+            bytes: Cell::new(0),
+            aborted: Cell::new(false),
+        }
+    }
+}
+
+impl<T: Display + ?Sized> Display for Traced<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        struct Adapter<'a, 'b, T: ?Sized> {
+            f: &'a mut Formatter<'b>,
+            traced: &'a Traced<'a, T>,
+        }
+
+        impl<T: ?Sized> Write for Adapter<'_, '_, T> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let bytes = &self.traced.bytes;
+                bytes.set(bytes.get() + s.len());
+                let ret = self.f.write_str(s);
+                if ret.is_err() {
+                    self.traced.aborted.set(true);
+                }
+                ret
+            }
+        }
+
+        self.aborted.set(false);
+        write!(Adapter { f, traced: self }, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes the string in chunks of `n` bytes.
+    struct Chunks<'a>(&'a str, usize);
+
+    impl Display for Chunks<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            self.0
+                .as_bytes()
+                .chunks(self.1)
+                .try_for_each(|chunk| f.write_str(std::str::from_utf8(chunk).unwrap()))
+        }
+    }
+
+    #[test]
+    fn short_circuit() {
+        let long = "abcdefghijklmnopqrstuvwxyz";
+
+        let (ret, stats) = cmp_traced("zzz", "aaa");
+        assert_eq!(ret, Ordering::Greater);
+        assert!(stats.short_circuited);
+        assert_eq!((stats.lhs_bytes, stats.rhs_bytes), (3, 3));
+
+        // The first chunks decide the result.
+        let (ret, stats) = cmp_traced(&Chunks(long, 1), &Chunks("zzz", 1));
+        assert_eq!(ret, Ordering::Less);
+        assert!(stats.short_circuited);
+        assert_eq!((stats.lhs_bytes, stats.rhs_bytes), (1, 1));
+
+        let (ret, stats) = cmp_traced(&Chunks(long, 4), &Chunks(long, 4));
+        assert_eq!(ret, Ordering::Equal);
+        assert!(!stats.short_circuited);
+        assert_eq!(stats.lhs_bytes, long.len());
+        // For each of the 7 chunks of `lhs`, `rhs` is formatted up to the end of the chunk and
+        // then one more chunk, which tells that `rhs` doesn't end there.
+        assert_eq!(stats.rhs_bytes, 8 + 12 + 16 + 20 + 24 + 26 + 26);
+
+        // `lhs` is a proper prefix of `rhs`, so `rhs` is not formatted to the end.
+        let (ret, stats) = cmp_traced("abc", long);
+        assert_eq!(ret, Ordering::Less);
+        assert!(stats.short_circuited);
+
+        // `lhs` is longer.
+        let (ret, stats) = cmp_traced(long, "abc");
+        assert_eq!(ret, Ordering::Greater);
+        assert!(stats.short_circuited);
+        assert_eq!((stats.lhs_bytes, stats.rhs_bytes), (long.len(), 3));
+
+        let (ret, stats) = cmp_traced("", "");
+        assert_eq!(ret, Ordering::Equal);
+        assert_eq!(stats, CmpStats::default());
+    }
+}
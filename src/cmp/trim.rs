@@ -0,0 +1,227 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
+
+use super::{cmp, eq, hash};
+
+/// A wrapper type that compares the inner value in its `Display` representation, ignoring leading
+/// and trailing ASCII whitespace.
+///
+/// This implements [`Eq`][std::cmp::Eq], [`Ord`][std::cmp::Ord] and [`Hash`][std::hash::Hash]
+/// traits with [`eq_trimmed`], [`cmp_trimmed`] and a trimming variant of [`hash`](super::hash())
+/// function. Whitespace between non-whitespace characters is compared verbatim.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::cmp::CmpTrimmed;
+///
+/// assert_eq!(CmpTrimmed(" foo "), CmpTrimmed("foo"));
+/// assert_ne!(CmpTrimmed("foo bar"), CmpTrimmed("foobar"));
+/// assert!(CmpTrimmed("\tbar") < CmpTrimmed("foo"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct CmpTrimmed<T: ?Sized = dyn Display>(pub T);
+
+impl<T: Display + ?Sized> Display for CmpTrimmed<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Display + ?Sized, U: Display + ?Sized> PartialEq<CmpTrimmed<U>> for CmpTrimmed<T> {
+    fn eq(&self, other: &CmpTrimmed<U>) -> bool {
+        eq_trimmed(&self.0, &other.0)
+    }
+}
+
+impl<T: Display + ?Sized> Eq for CmpTrimmed<T> {}
+
+impl<T: Display + ?Sized, U: Display + ?Sized> PartialOrd<CmpTrimmed<U>> for CmpTrimmed<T> {
+    fn partial_cmp(&self, other: &CmpTrimmed<U>) -> Option<Ordering> {
+        Some(cmp_trimmed(&self.0, &other.0))
+    }
+}
+
+impl<T: Display + ?Sized> Ord for CmpTrimmed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_trimmed(&self.0, &other.0)
+    }
+}
+
+impl<T: Display + ?Sized> Hash for CmpTrimmed<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash(&Trimmed(&self.0), state)
+    }
+}
+
+/// Tests two values for equality in their `Display` representations, ignoring leading and
+/// trailing ASCII whitespace.
+///
+/// This yields the same result as testing the `to_string()` outputs trimmed with
+/// `str::trim_matches(|c: char| c.is_ascii_whitespace())` for equality without heap allocation.
+///
+/// Each value is formatted once more to find where its trailing whitespace begins, because
+/// whether a run of whitespace is trailing is unknown until the representation ends.
+///
+/// ## Note
+///
+/// This has the same caveats as [`eq`](super::eq()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp::eq_trimmed(" Ferris\n", "Ferris"));
+/// ```
+#[must_use]
+pub fn eq_trimmed<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> bool {
+    eq(&Trimmed(lhs), &Trimmed(rhs))
+}
+
+/// Compares two values in their `Display` representations, ignoring leading and trailing ASCII
+/// whitespace.
+///
+/// This yields the same result as comparing the `to_string()` outputs trimmed with
+/// `str::trim_matches(|c: char| c.is_ascii_whitespace())` without heap allocation.
+///
+/// Each value is formatted once more to find where its trailing whitespace begins, because
+/// whether a run of whitespace is trailing is unknown until the representation ends.
+///
+/// ## Note
+///
+/// This has the same caveats as [`cmp`](super::cmp()) about the `Display` implementations.
+///
+/// ## Example
+///
+/// ```
+/// // `"foo" < "foo bar"` although `"foo " > "foo bar"`.
+/// assert!(fmt_cmp::cmp::cmp_trimmed("foo ", "foo bar").is_lt());
+/// ```
+#[must_use]
+pub fn cmp_trimmed<T: Display + ?Sized, U: Display + ?Sized>(lhs: &T, rhs: &U) -> Ordering {
+    cmp(&Trimmed(lhs), &Trimmed(rhs))
+}
+
+/// A `Display` adapter that strips leading and trailing ASCII whitespace.
+struct Trimmed<'a, T: ?Sized>(&'a T);
+
+impl<T: Display + ?Sized> Display for Trimmed<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        /// Finds the end of the last non-whitespace byte.
+        struct Measure {
+            len: usize,
+            end: usize,
+        }
+
+        impl Write for Measure {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                if let Some(i) = s.bytes().rposition(|b| !b.is_ascii_whitespace()) {
+                    self.end = self.len + i + 1;
+                }
+                self.len += s.len();
+                Ok(())
+            }
+        }
+
+        struct Adapter<'a, 'b> {
+            f: &'a mut Formatter<'b>,
+            pos: usize,
+            end: usize,
+            started: bool,
+        }
+
+        impl Write for Adapter<'_, '_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let start = self.pos;
+                self.pos += s.len();
+                if start >= self.end {
+                    return Ok(());
+                }
+                // `self.end` is at a `char` boundary since it is followed by an ASCII whitespace
+                // or the end of the representation.
+                let mut s = &s[..s.len().min(self.end - start)];
+                if !self.started {
+                    s = s.trim_start_matches(|c: char| c.is_ascii_whitespace());
+                    self.started = !s.is_empty();
+                }
+                self.f.write_str(s)
+            }
+        }
+
+        let mut measure = Measure { len: 0, end: 0 };
+        write!(measure, "{}", self.0)?;
+        write!(
+            Adapter {
+                f,
+                pos: 0,
+                end: measure.end,
+                started: false,
+            },
+            "{}",
+            self.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::testing::{hash_of, Chars};
+
+    fn trim(s: &str) -> &str {
+        s.trim_matches(|c: char| c.is_ascii_whitespace())
+    }
+
+    #[test]
+    fn matches_trimmed_str_cmp() {
+        #[track_caller]
+        fn check(x: &str, y: &str) {
+            let expected = trim(x).cmp(trim(y));
+            assert_eq!(cmp_trimmed(x, y), expected);
+            assert_eq!(cmp_trimmed(y, x), expected.reverse(), "rev");
+            assert_eq!(cmp_trimmed(&Chars(x), &Chars(y)), expected, "chars");
+            assert_eq!(eq_trimmed(x, y), trim(x) == trim(y));
+            assert_eq!(eq_trimmed(&Chars(x), y), trim(x) == trim(y), "chars");
+            assert_eq!(Trimmed(x).to_string(), trim(x));
+            assert_eq!(Trimmed(&Chars(x)).to_string(), trim(x), "chars");
+        }
+
+        // All whitespace.
+        check("", "");
+        check(" ", "");
+        check(" \t\r\n\x0c", "");
+        check("   ", "\n");
+        // Internal whitespace is preserved.
+        check("foo bar", "foobar");
+        check("foo  bar", "foo bar");
+        check(" foo bar ", "foo bar");
+        check("foo\tbar", "foo bar");
+        // Mixed.
+        check(" foo ", "foo");
+        check("\tfoo\n", " foo  ");
+        check("foo ", "foo bar");
+        check(" é ", "é");
+        check(" a b\u{3000}", "a b\u{3000}");
+        check("\u{a0}foo", "foo");
+        check("\x0bfoo", "foo");
+    }
+
+    #[test]
+    fn hash_matches_eq() {
+        assert_eq!(hash_of(&CmpTrimmed(" foo ")), hash_of(&CmpTrimmed("foo")));
+        assert_eq!(
+            hash_of(&CmpTrimmed(Chars("\tfoo"))),
+            hash_of(&CmpTrimmed("foo"))
+        );
+        assert_ne!(
+            hash_of(&CmpTrimmed("foo bar")),
+            hash_of(&CmpTrimmed("foobar"))
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! `const fn` variants of `cmp_dec` for the unsigned primitive integers.
+//!
+//! Our MSRV doesn't allow branches or loops in `const fn`, so the functions here are written
+//! without them, e.g., using `bool as usize` to index lookup tables instead of `if`.
+
+use std::cmp::Ordering;
+
+/// `POW10[i] == 10.pow(i)`.
+const POW10: [u128; 39] = [
+    1,
+    10,
+    100,
+    1000,
+    10000,
+    100000,
+    1000000,
+    10000000,
+    100000000,
+    1000000000,
+    10000000000,
+    100000000000,
+    1000000000000,
+    10000000000000,
+    100000000000000,
+    1000000000000000,
+    10000000000000000,
+    100000000000000000,
+    1000000000000000000,
+    10000000000000000000,
+    100000000000000000000,
+    1000000000000000000000,
+    10000000000000000000000,
+    100000000000000000000000,
+    1000000000000000000000000,
+    10000000000000000000000000,
+    100000000000000000000000000,
+    1000000000000000000000000000,
+    10000000000000000000000000000,
+    100000000000000000000000000000,
+    1000000000000000000000000000000,
+    10000000000000000000000000000000,
+    100000000000000000000000000000000,
+    1000000000000000000000000000000000,
+    10000000000000000000000000000000000,
+    100000000000000000000000000000000000,
+    1000000000000000000000000000000000000,
+    10000000000000000000000000000000000000,
+    100000000000000000000000000000000000000,
+];
+
+/// `ORDERINGS[a][b]` is `a.then(b)`, where the indices `0`, `1` and `2` stand for `Less`, `Equal`
+/// and `Greater` respectively.
+const ORDERINGS: [[Ordering; 3]; 3] = [
+    [Ordering::Less, Ordering::Less, Ordering::Less],
+    [Ordering::Less, Ordering::Equal, Ordering::Greater],
+    [Ordering::Greater, Ordering::Greater, Ordering::Greater],
+];
+
+/// Returns the index of `lhs.cmp(&rhs)` in `ORDERINGS`.
+const fn cmp_index(lhs: u128, rhs: u128) -> usize {
+    1 + (lhs > rhs) as usize - (lhs < rhs) as usize
+}
+
+/// Returns `value.ilog10()` for positive `value` and `0` for `value == 0`.
+const fn ilog10(value: u128) -> u32 {
+    macro_rules! count {
+        ($($i:expr)*) => { 0 $(+ (value >= POW10[$i]) as u32)* };
+    }
+    count!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38)
+}
+
+/// Returns `value.saturating_sub(rhs)`.
+const fn saturating_sub(lhs: u32, rhs: u32) -> u32 {
+    (lhs > rhs) as u32 * lhs.wrapping_sub(rhs)
+}
+
+const fn cmp_dec_u128_imp(lhs: u128, rhs: u128) -> Ordering {
+    let (lhs_log, rhs_log) = (ilog10(lhs), ilog10(rhs));
+    // Truncate the longer number to the length of the shorter one. If the truncated numbers are
+    // equal, the shorter one is a prefix of the longer one and thus compares less.
+    let lhs_trunc = lhs / POW10[saturating_sub(lhs_log, rhs_log) as usize];
+    let rhs_trunc = rhs / POW10[saturating_sub(rhs_log, lhs_log) as usize];
+    ORDERINGS[cmp_index(lhs_trunc, rhs_trunc)][cmp_index(lhs_log as u128, rhs_log as u128)]
+}
+
+macro_rules! imp {
+    ($($(#[$attr:meta])* $name:ident($ty:ty);)*) => {$(
+        $(#[$attr])*
+        #[must_use]
+        pub const fn $name(lhs: $ty, rhs: $ty) -> Ordering {
+            cmp_dec_u128_imp(lhs as u128, rhs as u128)
+        }
+    )*};
+}
+
+imp! {
+    /// Lexicographically compares the digits of two `u8`s in their decimal representation.
+    ///
+    /// This is a `const fn` variant of [`cmp_dec`](super::cmp_dec()), and yields the same result as
+    /// `lhs.to_string().cmp(&rhs.to_string())`.
+    cmp_dec_u8(u8);
+    /// Lexicographically compares the digits of two `u16`s in their decimal representation.
+    ///
+    /// This is a `const fn` variant of [`cmp_dec`](super::cmp_dec()), and yields the same result as
+    /// `lhs.to_string().cmp(&rhs.to_string())`.
+    cmp_dec_u16(u16);
+    /// Lexicographically compares the digits of two `u32`s in their decimal representation.
+    ///
+    /// This is a `const fn` variant of [`cmp_dec`](super::cmp_dec()), and yields the same result as
+    /// `lhs.to_string().cmp(&rhs.to_string())`.
+    cmp_dec_u32(u32);
+    /// Lexicographically compares the digits of two `u64`s in their decimal representation.
+    ///
+    /// This is a `const fn` variant of [`cmp_dec`](super::cmp_dec()), and yields the same result as
+    /// `lhs.to_string().cmp(&rhs.to_string())`. This is usable in constant expressions, e.g., to
+    /// check that a `static` lookup table is sorted, but [`cmp_dec`](super::cmp_dec()) is faster at
+    /// run time.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use fmt_cmp::int::cmp_dec_u64;
+    ///
+    /// const ORDERING: Ordering = cmp_dec_u64(42, 240);
+    /// assert_eq!(ORDERING, Ordering::Greater);
+    /// ```
+    cmp_dec_u64(u64);
+    /// Lexicographically compares the digits of two `u128`s in their decimal representation.
+    ///
+    /// This is a `const fn` variant of [`cmp_dec`](super::cmp_dec()), and yields the same result as
+    /// `lhs.to_string().cmp(&rhs.to_string())`.
+    cmp_dec_u128(u128);
+    /// Lexicographically compares the digits of two `usize`s in their decimal representation.
+    ///
+    /// This is a `const fn` variant of [`cmp_dec`](super::cmp_dec()), and yields the same result as
+    /// `lhs.to_string().cmp(&rhs.to_string())`.
+    cmp_dec_usize(usize);
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::vec;
+    use std::convert::TryFrom;
+
+    use super::*;
+
+    // Evaluated at compile time.
+    const _: () = assert!(cmp_dec_u64(42, 240).is_gt());
+    const _: () = assert!(cmp_dec_u64(42, 42).is_eq());
+    const _: () = assert!(cmp_dec_u8(9, 10).is_gt());
+    const _: () = assert!(cmp_dec_u128(u128::max_value(), 4).is_lt());
+
+    #[test]
+    fn matches_cmp_dec() {
+        #[track_caller]
+        fn check(x: u128, y: u128) {
+            let expected = crate::cmp_dec(x, y);
+            assert_eq!(cmp_dec_u128(x, y), expected, "{}, {}", x, y);
+            assert_eq!(cmp_dec_u128(y, x), expected.reverse(), "{}, {}, rev", x, y);
+            if let (Ok(x), Ok(y)) = (u64::try_from(x), u64::try_from(y)) {
+                assert_eq!(cmp_dec_u64(x, y), expected, "{}, {}, u64", x, y);
+            }
+            if let (Ok(x), Ok(y)) = (u8::try_from(x), u8::try_from(y)) {
+                assert_eq!(cmp_dec_u8(x, y), expected, "{}, {}, u8", x, y);
+            }
+        }
+
+        let mut values = vec![0, 1, 2, 9, 42, 99, 240, 255, u128::max_value()];
+        for &pow in POW10.iter() {
+            values.extend_from_slice(&[pow - 1, pow, pow + 1, pow * 2]);
+            values.extend(pow.checked_mul(9));
+        }
+        for &x in &values {
+            for &y in &values {
+                check(x, y);
+            }
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Integer logarithm and power utilities used by the comparison functions.
+
+use super::traits::private::Primitive;
+use super::{FmtInteger, Integer, PrimitiveInteger};
+
+/// Returns the logarithm of the absolute value of `n` with respect to `base`, rounded down.
+///
+/// In other words, this returns the number of digits of `n` in radix `base`, minus one. Unlike the
+/// `ilog` methods of the integer primitives, this returns `0` instead of panicking if `n` is zero,
+/// i.e., `ilog(0, base) == 0`, consistently with zero having one digit.
+///
+/// ## Panics
+///
+/// Panics if `base < 2`.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::math::ilog;
+///
+/// assert_eq!(ilog(0xff_u32, 16), 1);
+/// assert_eq!(ilog(0x100_u32, 16), 2);
+/// assert_eq!(ilog(-8_i8, 2), 3);
+/// assert_eq!(ilog(0_u8, 2), 0);
+/// ```
+#[must_use]
+pub fn ilog<T: Integer>(n: T, base: u32) -> u32 {
+    if base < 2 {
+        panic!("`base` must be at least 2");
+    }
+
+    let (_, n) = n.split_sign();
+    n.checked_log(base).unwrap_or(0)
+}
+
+/// Returns the base 10 logarithm of the absolute value of `n`, rounded down.
+///
+/// This is the same as `ilog(n, 10)` but faster. Like [`ilog`], this returns `0` if `n` is zero.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::math::ilog10;
+///
+/// assert_eq!(ilog10(0_u32), 0);
+/// assert_eq!(ilog10(99_u32), 1);
+/// assert_eq!(ilog10(-100_i32), 2);
+/// ```
+#[must_use]
+pub fn ilog10<T: Integer>(n: T) -> u32 {
+    let (_, n) = n.split_sign();
+    n.checked_log10().unwrap_or(0)
+}
+
+/// Returns `n / base.pow(exp)`, rounded toward zero.
+///
+/// Unlike `n / base.pow(exp)`, this never overflows even if `base.pow(exp)` does not fit in `T`.
+///
+/// Unlike [`ilog`] and [`ilog10`], this only accepts the integer primitives and not every
+/// [`Integer`]. [`FmtInteger::div_pow`] is only specified for `exp` up to `ilog(n, base)`, and
+/// `FmtInteger` provides no way to make the zero that this returns for a greater `exp`. For a
+/// `FmtInteger` type, call `FmtInteger::div_pow` directly instead, with `exp` in that range.
+///
+/// ## Panics
+///
+/// Panics if `base == 0`.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::math::div_pow;
+///
+/// assert_eq!(div_pow(12345_u32, 10, 2), 123);
+/// assert_eq!(div_pow(-12345_i32, 10, 2), -123);
+/// // `10_u128.pow(40)` would overflow.
+/// assert_eq!(div_pow(u128::MAX, 10, 38), 3);
+/// assert_eq!(div_pow(u128::MAX, 10, 40), 0);
+/// ```
+#[must_use]
+pub fn div_pow<T: PrimitiveInteger>(n: T, base: u32, exp: u32) -> T {
+    if base == 0 {
+        panic!("`base` must be greater than 0");
+    }
+
+    Primitive::div_pow(n, base, exp)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::ToString;
+    use std::fmt::Display;
+
+    use super::*;
+
+    #[test]
+    fn ilog10_matches_str_len() {
+        #[track_caller]
+        fn check<T: Copy + Display + Integer>(n: T) {
+            let expected = n.to_string().trim_start_matches('-').len() as u32 - 1;
+            assert_eq!(ilog10(n), expected, "{}", n);
+            assert_eq!(ilog(n, 10), expected, "{},ilog", n);
+        }
+
+        check(u8::MAX);
+        check(u16::MAX);
+        check(u32::MAX);
+        check(u64::MAX);
+        check(u128::MAX);
+        check(usize::MAX);
+        check(i8::MAX);
+        check(i16::MAX);
+        check(i32::MAX);
+        check(i64::MAX);
+        check(i128::MAX);
+        check(isize::MAX);
+        check(i8::MIN);
+        check(i16::MIN);
+        check(i32::MIN);
+        check(i64::MIN);
+        check(i128::MIN);
+        check(isize::MIN);
+        check(0_u32);
+        check(9_u32);
+        check(10_u32);
+    }
+
+    #[test]
+    fn div_pow_matches_div() {
+        #[track_caller]
+        fn check(n: i128, base: u32, exp: u32) {
+            let expected = i128::from(base).checked_pow(exp).map_or(0, |pow| n / pow);
+            assert_eq!(div_pow(n, base, exp), expected, "{},{},{}", n, base, exp);
+            if n >= 0 {
+                let expected = expected as u128;
+                assert_eq!(
+                    div_pow(n as u128, base, exp),
+                    expected,
+                    "{},{},{},u",
+                    n,
+                    base,
+                    exp
+                );
+            }
+        }
+
+        for &n in &[
+            0,
+            1,
+            9,
+            10,
+            11,
+            99,
+            100,
+            12345,
+            -1,
+            -10,
+            -12345,
+            i128::MAX,
+            i128::MIN + 1,
+        ] {
+            for &base in &[1, 2, 10, 16, 255, 256, u32::MAX] {
+                for exp in 0..=130 {
+                    check(n, base, exp);
+                }
+            }
+        }
+
+        assert_eq!(div_pow(i8::MIN, 2, 7), -1);
+        assert_eq!(div_pow(i8::MIN, 2, 0), i8::MIN);
+        assert_eq!(div_pow(i8::MIN, 10, 3), 0);
+        assert_eq!(div_pow(u8::MAX, 256, 1), 0);
+        assert_eq!(div_pow(u8::MAX, 256, 0), u8::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog_base_one() {
+        let _ = ilog(42_u32, 1);
+    }
+}
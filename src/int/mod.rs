@@ -1,31 +1,50 @@
 //! Lexicographic comparison utility for integers.
 
+pub mod math;
+
+mod const_dec;
+mod radix;
 mod traits;
 
-pub use self::traits::Integer;
+pub use self::const_dec::{
+    cmp_dec_u128, cmp_dec_u16, cmp_dec_u32, cmp_dec_u64, cmp_dec_u8, cmp_dec_usize,
+};
+pub use self::radix::{Bin, Dec, Hex, Oct, Radix};
+pub use self::traits::{FmtInteger, Integer, PrimitiveInteger, SignedInteger};
 
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 
 macro_rules! imp {
-    ($lhs:expr, $rhs:expr, |$min:ident, $max:ident| $align:expr) => {{
-        let (lhs, rhs) = ($lhs, $rhs);
+    ($lhs:expr, $rhs:expr, |$min:ident, $max:ident $(, $is_negative:ident)?| $align:expr) => {{
+        let (lhs_is_negative, lhs) = $lhs.split_sign();
+        let (rhs_is_negative, rhs) = $rhs.split_sign();
 
-        let (lhs, rhs, reversed) = if lhs.copy().lt(rhs.copy()) {
-            (rhs, lhs, true)
-        } else if lhs.copy().eq(rhs.copy()) {
-            return Ordering::Equal;
-        } else {
-            (lhs, rhs, false)
+        // Since `'-' < '0'` holds, a negative number always compares less than a non-negative
+        // number. Otherwise, both numbers have the same sign (if any), so we only need to compare
+        // the digits of their absolute values.
+        match (lhs_is_negative, rhs_is_negative) {
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            _ => {}
+        }
+
+        let (lhs, rhs, reversed) = match lhs.cmp(&rhs) {
+            Ordering::Less => (rhs, lhs, true),
+            Ordering::Equal => return Ordering::Equal,
+            Ordering::Greater => (lhs, rhs, false),
         };
 
         // Align the number of digits to make numerical comparison equivalent to lexicographical
         // comparison. Since `'0' < '9' < 'A' < 'Z' (< 'a' < 'z')` holds, we don't need to
         // special-case radixes greater than 10.
-        let lhs = match (lhs.copy(), rhs.copy()) {
-            ($max, $min) => $align,
+        let lhs = {
+            $(let $is_negative = lhs_is_negative;)?
+            let ($max, $min) = (lhs, &rhs);
+            $align
         };
 
-        if lhs.lt(rhs) ^ reversed {
+        if (lhs < rhs) ^ reversed {
             Ordering::Less
         } else {
             // We've ruled out the case that the input `lhs` equals the input `rhs`, so if `lhs`
@@ -38,15 +57,31 @@ macro_rules! imp {
 /// Lexicographically compares the digits of two integers.
 ///
 /// While being able to compare numbers in arbitrary radix, this is not optimized very well.
-/// You should use [`cmp_dec`] for comparing in decimal representation or
-/// <code>[fmt_cmp::cmp](crate::cmp())`(&format_args!("{:X}", lhs), &format_args!("{:X}", rhs))`</code>
-/// for comparing in hexadecimal representation (`"{:o}"` for octal) instead.
+/// You should use [`cmp_dec`] for comparing in decimal representation instead.
+///
+/// Note that unlike [`cmp_hex`], [`cmp_oct`] and [`cmp_bin`], this compares negative numbers in
+/// the sign-magnitude representation, i.e., `cmp_int(lhs, rhs, 16)` is not equivalent to
+/// `cmp_hex(lhs, rhs)` for negative numbers.
+///
+/// Negative numbers are compared as their absolute values prefixed with `-`, like their `Display`
+/// representations.
 ///
 /// When `radix == 1`, this will compare digits in the [unary system], i.e., will return the same
-/// result as `lhs.cmp(&rhs)`.
+/// result as `lhs.cmp(&rhs)` for non-negative numbers.
+///
+/// In general, this compares the sequences of the digit values (from the most significant digit)
+/// lexicographically, i.e., a sequence is less than another if it is a proper prefix of the other
+/// or if it has a smaller digit at the first position where they differ. Each digit value is
+/// ordered by its numerical value, which agrees with the ordering of the digit characters
+/// `0-9a-z` (or `0-9A-Z`) when `radix <= 36`. When `radix > 36`, the digits have no character
+/// representation, but the comparison is still defined in terms of the digit values, i.e.,
+/// the digit of value `d + 1` compares greater than the digit of value `d`.
 ///
-/// When `radix > 36`, this will compare digits in a theoretical _base-`radix` system_, in which
-/// the `radix`-th digit compares greater than the `(radix-1)`-th digit.
+/// Since both casings order the letters the same as the digit values and place them after `9`, the
+/// result does not depend on the casing. For example, `cmp_int(lhs, rhs, 16)` agrees with both
+/// `format!("{:x}", lhs).cmp(&format!("{:x}", rhs))` and
+/// `format!("{:X}", lhs).cmp(&format!("{:X}", rhs))` for non-negative numbers, so no separate
+/// functions are needed for the lowercase and uppercase representations.
 ///
 /// ## Panics
 ///
@@ -65,13 +100,50 @@ macro_rules! imp {
 /// [unary system]: <https://en.wikipedia.org/wiki/Unary_numeral_system>
 #[must_use]
 pub fn cmp_int<T: Integer>(lhs: T, rhs: T, radix: u32) -> Ordering {
+    cmp_int_imp(lhs, rhs, radix)
+}
+
+/// Lexicographically compares the digits of two integers in the radix given by `R`.
+///
+/// This is the same as [`cmp_int`] except that the radix is given at compile time, which lets the
+/// compiler specialize the comparison for the radix. In particular, `cmp_radix::<Dec, _>` is the
+/// same as [`cmp_dec`], which is faster than `cmp_int(_, _, 10)`.
+///
+/// ## Panics
+///
+/// Panics if `R::RADIX == 0`.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::{cmp_radix, Hex};
+///
+/// assert!(cmp_radix::<Hex, u32>(0x2a, 0x9).is_lt());
+/// assert!(cmp_radix::<Hex, u32>(0xa2, 0x9).is_gt());
+/// ```
+#[must_use]
+pub fn cmp_radix<R: Radix, T: Integer>(lhs: T, rhs: T) -> Ordering {
+    if R::RADIX == 10 {
+        // Take the specialized `ilog10`.
+        cmp_dec(lhs, rhs)
+    } else {
+        cmp_int_imp(lhs, rhs, R::RADIX)
+    }
+}
+
+// Inlined into `cmp_radix` so that `radix` is a constant there.
+#[inline(always)]
+fn cmp_int_imp<T: Integer>(lhs: T, rhs: T, radix: u32) -> Ordering {
     if radix == 0 {
         panic!("`radix` must be greater than 0");
     }
 
-    imp!(lhs, rhs, |min, max| max
-        .copy()
-        .invpow(radix, max.ilog(radix) - min.ilog(radix)))
+    imp!(lhs, rhs, |min, max| if radix == 1 {
+        max
+    } else {
+        let exp = log(&max, radix) - log(min, radix);
+        div_pow(max, radix, exp)
+    })
 }
 
 /// Lexicographically compares the digits of two integers in their decimal representation.
@@ -83,12 +155,462 @@ pub fn cmp_int<T: Integer>(lhs: T, rhs: T, radix: u32) -> Ordering {
 /// ```
 /// assert!(fmt_cmp::cmp_dec::<u32>(42, 3).is_gt());
 /// assert!(fmt_cmp::cmp_dec::<u32>(24, 3).is_lt());
+///
+/// assert!(fmt_cmp::cmp_dec::<i32>(-42, -3).is_gt());
+/// assert!(fmt_cmp::cmp_dec::<i32>(-42, 3).is_lt());
 /// ```
 #[must_use]
 pub fn cmp_dec<T: Integer>(lhs: T, rhs: T) -> Ordering {
-    imp!(lhs, rhs, |min, max| max
-        .copy()
-        .invpow(10_u32, max.ilog10() - min.ilog10()))
+    imp!(lhs, rhs, |min, max| {
+        let exp = log10(&max) - log10(min);
+        div_pow(max, 10, exp)
+    })
+}
+
+/// Lexicographically compares the digits of two integers behind references in their decimal
+/// representation.
+///
+/// This is the same as [`cmp_dec`] except that this takes the integers by reference (and clones
+/// them), which makes it usable as a comparator of `slice::sort_by` without dereferencing.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::cmp_dec_ref;
+///
+/// let mut values = [42_u64, 240, 3];
+/// values.sort_by(cmp_dec_ref);
+/// assert_eq!(values, [240, 3, 42]);
+/// ```
+#[must_use]
+pub fn cmp_dec_ref<T: Integer + Clone>(lhs: &T, rhs: &T) -> Ordering {
+    cmp_dec(lhs.clone(), rhs.clone())
+}
+
+/// Lexicographically compares the digits of two integers behind references.
+///
+/// This is the same as [`cmp_int`] except that this takes the integers by reference (and clones
+/// them).
+///
+/// ## Panics
+///
+/// Panics if `radix == 0`.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::cmp_int_ref;
+///
+/// let mut values = [0xa2_u32, 0x9, 0x2a];
+/// values.sort_by(|a, b| cmp_int_ref(a, b, 16));
+/// assert_eq!(values, [0x2a, 0x9, 0xa2]);
+/// ```
+#[must_use]
+pub fn cmp_int_ref<T: Integer + Clone>(lhs: &T, rhs: &T, radix: u32) -> Ordering {
+    cmp_int(lhs.clone(), rhs.clone(), radix)
+}
+
+/// Lexicographically compares the digits of two integers of possibly different types in their
+/// decimal representation.
+///
+/// This yields the same result as `lhs.to_string().cmp(&rhs.to_string())` without heap allocation.
+/// Unlike [`cmp_dec`], this accepts different types for `lhs` and `rhs`, including mixes of signed
+/// and unsigned types that cannot be converted into each other losslessly.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::cmp_dec_mixed;
+///
+/// // `"255" < "3"`
+/// assert!(cmp_dec_mixed(u8::MAX, 3_u64).is_lt());
+/// // `"-1" < "1"`
+/// assert!(cmp_dec_mixed(-1_i8, u128::MAX).is_lt());
+/// ```
+#[must_use]
+pub fn cmp_dec_mixed<T: PrimitiveInteger, U: PrimitiveInteger>(lhs: T, rhs: U) -> Ordering {
+    // Every primitive fits in `u128` in the sign-magnitude representation, and the decimal
+    // representation only depends on the sign and the magnitude.
+    let (lhs_is_negative, lhs) = lhs.split_sign_u128();
+    let (rhs_is_negative, rhs) = rhs.split_sign_u128();
+    match (lhs_is_negative, rhs_is_negative) {
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        _ => cmp_dec(lhs, rhs),
+    }
+}
+
+/// Lexicographically compares two signed integers in their decimal representation.
+///
+/// This is the same as [`cmp_dec`] restricted to signed integers, i.e., this yields the same
+/// result as `lhs.to_string().cmp(&rhs.to_string())`, which amounts to the following order:
+///
+/// 1. A negative number compares less than a non-negative number, since `'-' < '0'`.
+/// 2. Two non-negative numbers compare like their digits, i.e., like [`cmp_dec`] on unsigned
+///    integers.
+/// 3. Two negative numbers compare like the digits of their absolute values (not reversed), since
+///    their representations share the leading `-`. For example, `-10` compares less than `-5`
+///    because `"10" < "5"`, and `-50` compares greater than `-5` because `"5"` is a prefix of
+///    `"50"`.
+///
+/// In particular, `-n` always compares less than `n` for `n > 0`, and the order is independent
+/// of the numeric order of the values.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::cmp_signed_dec;
+///
+/// // `"-5" < "5"`
+/// assert!(cmp_signed_dec(-5, 5).is_lt());
+/// // `"-10" < "-5"`
+/// assert!(cmp_signed_dec(-10, -5).is_lt());
+/// // `"-50" > "-5"`
+/// assert!(cmp_signed_dec(-50, -5).is_gt());
+/// // `"-1" < "0"`
+/// assert!(cmp_signed_dec(-1, 0).is_lt());
+/// ```
+#[must_use]
+pub fn cmp_signed_dec<T: SignedInteger>(lhs: T, rhs: T) -> Ordering {
+    cmp_dec(lhs, rhs)
+}
+
+/// Lexicographically compares the digits of two integers in their zero-padded decimal
+/// representation.
+///
+/// This yields the same result as
+/// `format!("{:0width$}", lhs, width = width).cmp(&format!("{:0width$}", rhs, width = width))`
+/// without heap allocation. Like the formatting, the sign of a negative number counts toward
+/// `width`, and a number wider than `width` is not padded.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::cmp_dec_padded;
+///
+/// // `"0042" < "0240"`
+/// assert!(cmp_dec_padded::<u32>(42, 240, 4).is_lt());
+/// // `"42" > "240"`
+/// assert!(cmp_dec_padded::<u32>(42, 240, 2).is_gt());
+/// // `"12345" > "0240"`
+/// assert!(cmp_dec_padded::<u32>(12345, 240, 4).is_gt());
+/// // `"-042" < "0042"`
+/// assert!(cmp_dec_padded::<i32>(-42, 42, 4).is_lt());
+/// ```
+#[must_use]
+pub fn cmp_dec_padded<T: Integer>(lhs: T, rhs: T, width: usize) -> Ordering {
+    imp!(lhs, rhs, |min, max, is_negative| {
+        let width = if is_negative {
+            // The sign counts toward the width.
+            width.saturating_sub(1)
+        } else {
+            width
+        };
+        let width = u32::try_from(width).unwrap_or(u32::max_value());
+        // Each side is as wide as the wider of its digits and `width`. Padded numbers of the same
+        // width compare like the numbers themselves, so we only need to align the number that
+        // exceeds `width`.
+        let exp = (log10(&max) + 1).max(width) - (log10(min) + 1).max(width);
+        div_pow(max, 10, exp)
+    })
+}
+
+/// Sorts a slice of integers lexicographically by their decimal representations.
+///
+/// This yields the same result as `slice.sort_by(|a, b| fmt_cmp::cmp_dec(*a, *b))`, but computes
+/// the number of digits of each element only once beforehand instead of on every comparison,
+/// at the cost of allocating a buffer of the same length as `slice`.
+///
+/// ## Example
+///
+/// ```
+/// let mut values: Vec<u64> = (1..=10).collect();
+/// fmt_cmp::int::sort_dec(&mut values);
+/// assert_eq!(values, [1, 10, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sort_dec<T: Integer + Copy>(slice: &mut [T]) {
+    let mut keyed: alloc::vec::Vec<(u32, T)> =
+        slice.iter().map(|&v| (num_digits(v, 10), v)).collect();
+    // Integers with the same decimal representation are equal, so stability doesn't matter.
+    keyed.sort_unstable_by(|&(lhs_digits, lhs), &(rhs_digits, rhs)| {
+        let (lhs_is_negative, lhs) = lhs.split_sign();
+        let (rhs_is_negative, rhs) = rhs.split_sign();
+        match (lhs_is_negative, rhs_is_negative) {
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            _ => {}
+        }
+
+        // Truncate the longer one to the length of the shorter one like `cmp_dec` does. If they
+        // are equal after the truncation, the longer one is greater.
+        match lhs_digits.cmp(&rhs_digits) {
+            Ordering::Less => lhs
+                .cmp(&rhs.div_pow(10, rhs_digits - lhs_digits))
+                .then(Ordering::Less),
+            Ordering::Equal => lhs.cmp(&rhs),
+            Ordering::Greater => lhs
+                .div_pow(10, lhs_digits - rhs_digits)
+                .cmp(&rhs)
+                .then(Ordering::Greater),
+        }
+    });
+    for (dst, (_, v)) in slice.iter_mut().zip(keyed) {
+        *dst = v;
+    }
+}
+
+/// Lexicographically compares two numbers given as sequences of digit values.
+///
+/// Each slice holds the base-`radix` digit values of a non-negative number, from the most
+/// significant digit. Leading zeros are ignored (an empty or all-zero slice represents the single
+/// digit `0`), so this yields the same result as comparing the representations of the numbers
+/// without leading zeros, like [`cmp_int`] does, e.g., `cmp_digits(&[0, 4, 2], &[2, 4, 0], 10)` is
+/// the same as `cmp_dec(42, 240)`.
+///
+/// Every digit value must be less than `radix`, which is checked only in debug builds.
+///
+/// ## Panics
+///
+/// Panics if `radix == 0`.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::cmp_digits;
+///
+/// // `"42" > "240"`
+/// assert!(cmp_digits(&[0, 4, 2], &[2, 4, 0], 10).is_gt());
+/// // `"0" < "1"`
+/// assert!(cmp_digits(&[], &[1], 2).is_lt());
+/// // `"fe" > "f"`
+/// assert!(cmp_digits(&[15, 14], &[15], 16).is_gt());
+/// ```
+#[must_use]
+pub fn cmp_digits(lhs: &[u8], rhs: &[u8], radix: u8) -> Ordering {
+    fn normalize(digits: &[u8], radix: u8) -> &[u8] {
+        debug_assert!(
+            digits.iter().all(|&d| d < radix),
+            "digit out of range for radix {}: {:?}",
+            radix,
+            digits,
+        );
+        match digits.iter().position(|&d| d != 0) {
+            Some(i) => &digits[i..],
+            None => &[0],
+        }
+    }
+
+    if radix == 0 {
+        panic!("`radix` must be greater than 0");
+    }
+
+    normalize(lhs, radix).cmp(normalize(rhs, radix))
+}
+
+/// Lexicographically compares two integers in their representations with a custom alphabet.
+///
+/// Each number is represented in base `alphabet.len()` from the most significant digit, where the
+/// digit value `d` is written as the byte `alphabet[d]` and a negative number is prefixed with
+/// `b'-'`. Zero is represented as the single byte `alphabet[0]`. This compares the byte sequences
+/// lexicographically without building them, so the glyphs may be in any order, e.g., base-58 or
+/// an alphabet where `b'Z'` represents the digit value `0`.
+///
+/// Unlike [`cmp_int`], this only accepts the primitive integers, since it needs the remainders of
+/// the divisions to extract the digits.
+///
+/// ## Panics
+///
+/// Panics if `alphabet.len() < 2`.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::cmp_int_alphabet;
+///
+/// const BASE58: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// // `"z" > "21"`
+/// assert!(cmp_int_alphabet(57_u32, 58, BASE58).is_gt());
+///
+/// // A decimal alphabet in the reverse order.
+/// const REVERSED: &[u8] = b"9876543210";
+/// // `"7" < "8"`
+/// assert!(cmp_int_alphabet(2_u32, 1, REVERSED).is_lt());
+/// // `"89" > "8"`
+/// assert!(cmp_int_alphabet(10_u32, 1, REVERSED).is_gt());
+/// ```
+#[must_use]
+pub fn cmp_int_alphabet<T: PrimitiveInteger>(lhs: T, rhs: T, alphabet: &[u8]) -> Ordering {
+    /// An iterator over the bytes of the representation of a number.
+    struct Glyphs<'a> {
+        alphabet: &'a [u8],
+        sign: bool,
+        value: u128,
+        /// The place value of the next digit, or zero if all the digits have been yielded.
+        place: u128,
+    }
+
+    impl Iterator for Glyphs<'_> {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            if self.sign {
+                self.sign = false;
+                return Some(b'-');
+            }
+            if self.place == 0 {
+                return None;
+            }
+            let base = self.alphabet.len() as u128;
+            let digit = self.value / self.place % base;
+            self.place /= base;
+            Some(self.alphabet[digit as usize])
+        }
+    }
+
+    fn glyphs<T: PrimitiveInteger>(value: T, alphabet: &[u8]) -> Glyphs<'_> {
+        let (is_negative, value) = value.split_sign_u128();
+        let base = alphabet.len() as u128;
+        let mut place = 1;
+        while place <= value / base {
+            place *= base;
+        }
+        Glyphs {
+            alphabet,
+            sign: is_negative,
+            value,
+            place,
+        }
+    }
+
+    if alphabet.len() < 2 {
+        panic!("`alphabet` must have at least 2 digits");
+    }
+
+    glyphs(lhs, alphabet).cmp(glyphs(rhs, alphabet))
+}
+
+/// Returns the number of digits of an integer in the given radix.
+///
+/// The sign of a negative number is not counted, i.e., for `radix == 10`, this yields the same
+/// result as `value.to_string().trim_start_matches('-').len()`. Zero is considered to have one
+/// digit.
+///
+/// When `radix == 1`, this always returns `1`. The [unary system] would represent `value` with
+/// `value` digits, which may not fit in `u32`, so the whole value is treated as a single digit
+/// instead, consistently with how [`cmp_int`] compares values in the unary system.
+///
+/// ## Panics
+///
+/// Panics if `radix == 0`.
+///
+/// ## Example
+///
+/// ```
+/// assert_eq!(fmt_cmp::int::num_digits(0_u32, 10), 1);
+/// assert_eq!(fmt_cmp::int::num_digits(42_u32, 10), 2);
+/// assert_eq!(fmt_cmp::int::num_digits(-42_i32, 10), 2);
+/// assert_eq!(fmt_cmp::int::num_digits(0xff_u32, 16), 2);
+/// assert_eq!(fmt_cmp::int::num_digits(0xff_u32, 2), 8);
+/// ```
+///
+/// [unary system]: <https://en.wikipedia.org/wiki/Unary_numeral_system>
+#[must_use]
+pub fn num_digits<T: Integer>(value: T, radix: u32) -> u32 {
+    if radix == 0 {
+        panic!("`radix` must be greater than 0");
+    }
+
+    let (_, value) = value.split_sign();
+    if radix == 1 {
+        1
+    } else if radix == 10 {
+        log10(&value) + 1
+    } else {
+        log(&value, radix) + 1
+    }
+}
+
+/// Lexicographically compares the digits of two integers in their hexadecimal representation.
+///
+/// This yields the same result as `format!("{:x}", lhs).cmp(&format!("{:x}", rhs))` without heap
+/// allocation. Since the lowercase and uppercase hexadecimal digits are both ordered after the
+/// decimal digits, this also yields the same result as the uppercase (`"{:X}"`) counterpart.
+///
+/// Like the `LowerHex` implementations of the signed integer primitives, this compares negative
+/// numbers in their two's complement representation.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp_hex::<u32>(0x2a, 0x9).is_lt());
+/// assert!(fmt_cmp::cmp_hex::<u32>(0xa2, 0x9).is_gt());
+///
+/// // `"ff" > "7f"`
+/// assert!(fmt_cmp::cmp_hex::<i8>(-1, 0x7f).is_gt());
+/// ```
+#[must_use]
+pub fn cmp_hex<T: Integer>(lhs: T, rhs: T) -> Ordering {
+    cmp_int(lhs.to_unsigned(), rhs.to_unsigned(), 16)
+}
+
+/// Lexicographically compares the digits of two integers in their octal representation.
+///
+/// This yields the same result as `format!("{:o}", lhs).cmp(&format!("{:o}", rhs))` without heap
+/// allocation.
+///
+/// Like the `Octal` implementations of the signed integer primitives, this compares negative
+/// numbers in their two's complement representation.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp_oct::<u32>(0o42, 0o3).is_gt());
+/// assert!(fmt_cmp::cmp_oct::<u32>(0o24, 0o3).is_lt());
+/// ```
+#[must_use]
+pub fn cmp_oct<T: Integer>(lhs: T, rhs: T) -> Ordering {
+    cmp_int(lhs.to_unsigned(), rhs.to_unsigned(), 8)
+}
+
+/// Lexicographically compares the digits of two integers in their binary representation.
+///
+/// This yields the same result as `format!("{:b}", lhs).cmp(&format!("{:b}", rhs))` without heap
+/// allocation.
+///
+/// Like the `Binary` implementations of the signed integer primitives, this compares negative
+/// numbers in their two's complement representation.
+///
+/// ## Example
+///
+/// ```
+/// assert!(fmt_cmp::cmp_bin::<u32>(0b10, 0b1).is_gt());
+/// assert!(fmt_cmp::cmp_bin::<u32>(0b10, 0b11).is_lt());
+/// ```
+#[must_use]
+pub fn cmp_bin<T: Integer>(lhs: T, rhs: T) -> Ordering {
+    cmp_int(lhs.to_unsigned(), rhs.to_unsigned(), 2)
+}
+
+fn log<T: FmtInteger>(value: &T, base: u32) -> u32 {
+    value.checked_log(base).unwrap_or(0)
+}
+
+fn log10<T: FmtInteger>(value: &T) -> u32 {
+    value.checked_log10().unwrap_or(0)
+}
+
+/// Calls `value.div_pow(base, exp)` unless `exp` is zero.
+///
+/// The numbers being compared commonly have the same number of digits, in which case the numerical
+/// comparison already agrees with the lexicographical one. This saves the `div_pow` call in that
+/// case, which may not be trivial for a third-party `FmtInteger` like a big integer.
+fn div_pow<T: FmtInteger>(value: T, base: u32, exp: u32) -> T {
+    if exp == 0 {
+        value
+    } else {
+        value.div_pow(base, exp)
+    }
 }
 
 #[cfg(test)]
@@ -96,10 +618,68 @@ mod tests {
     #[cfg(not(feature = "alloc"))]
     extern crate alloc;
 
+    use alloc::format;
     use alloc::string::ToString;
+    use std::fmt::{self, Binary, Debug, Display, Formatter, LowerHex, Octal};
 
     use super::*;
 
+    #[test]
+    fn cmp_digits_matches_cmp_int() {
+        fn digits(mut value: u64, radix: u8, leading_zeros: usize) -> alloc::vec::Vec<u8> {
+            let mut ret = alloc::vec![0; leading_zeros];
+            let start = ret.len();
+            loop {
+                ret.insert(start, (value % u64::from(radix)) as u8);
+                value /= u64::from(radix);
+                if value == 0 {
+                    return ret;
+                }
+            }
+        }
+
+        let values = [
+            0,
+            1,
+            2,
+            9,
+            10,
+            11,
+            42,
+            99,
+            100,
+            240,
+            420,
+            4200,
+            u64::max_value(),
+        ];
+        for &radix in &[2, 8, 10, 16, 36] {
+            for &lhs in &values {
+                for &rhs in &values {
+                    let expected = cmp_int(lhs, rhs, radix.into());
+                    if radix == 10 {
+                        assert_eq!(cmp_dec(lhs, rhs), expected);
+                    }
+                    for &(lzeros, rzeros) in &[(0, 0), (1, 0), (0, 2), (3, 1)] {
+                        let (l, r) = (digits(lhs, radix, lzeros), digits(rhs, radix, rzeros));
+                        assert_eq!(
+                            cmp_digits(&l, &r, radix),
+                            expected,
+                            "{:?}, {:?}, {}",
+                            l,
+                            r,
+                            radix
+                        );
+                    }
+                }
+            }
+        }
+
+        assert_eq!(cmp_digits(&[], &[0], 10), Ordering::Equal);
+        assert_eq!(cmp_digits(&[], &[0, 0], 10), Ordering::Equal);
+        assert_eq!(cmp_digits(&[], &[1], 10), Ordering::Less);
+    }
+
     #[test]
     fn matches_str_cmp() {
         #[track_caller]
@@ -194,4 +774,880 @@ mod tests {
         check(u128::MAX, 1);
         check(u128::MAX, u128::MAX - 1);
     }
+
+    #[test]
+    fn matches_str_cmp_signed() {
+        #[track_caller]
+        fn check<T: Copy + Integer + Ord + ToString>(lhs: T, rhs: T) {
+            let expected = lhs.to_string().cmp(&rhs.to_string());
+            assert_eq!(cmp_int(lhs, rhs, 10), expected);
+            assert_eq!(cmp_int(rhs, lhs, 10), expected.reverse(), "reverse");
+            assert_eq!(cmp_dec(lhs, rhs), expected, "dec");
+            assert_eq!(cmp_dec(rhs, lhs), expected.reverse(), "dec,reverse");
+        }
+
+        let values = [
+            0_i64, 1, 2, 4, 5, 9, 10, 20, 24, 40, 41, 42, 43, 50, 52, 200, 240, 241, 410, 420, 421,
+            430, 500, 542, 2410, 4100, 4200, 4211, 5420,
+        ];
+        for &lhs in &values {
+            for &rhs in &values {
+                check(lhs, rhs);
+                check(-lhs, rhs);
+                check(lhs, -rhs);
+                check(-lhs, -rhs);
+            }
+        }
+
+        // Works with min and max values.
+        check(i8::MIN, i8::MAX);
+        check(i8::MIN, -1);
+        check(i8::MIN, i8::MIN + 1);
+        check(i16::MIN, i16::MAX);
+        check(i16::MIN, -1);
+        check(i16::MIN, i16::MIN + 1);
+        check(i32::MIN, i32::MAX);
+        check(i32::MIN, -1);
+        check(i32::MIN, i32::MIN + 1);
+        check(i64::MIN, i64::MAX);
+        check(i64::MIN, -1);
+        check(i64::MIN, i64::MIN + 1);
+        check(isize::MIN, isize::MAX);
+        check(isize::MIN, -1);
+        check(isize::MIN, isize::MIN + 1);
+        check(i128::MIN, i128::MAX);
+        check(i128::MIN, -1);
+        check(i128::MIN, i128::MIN + 1);
+    }
+
+    #[test]
+    fn matches_radix_fmt() {
+        #[track_caller]
+        fn check<T: Binary + Copy + Integer + LowerHex + Octal>(lhs: T, rhs: T) {
+            let expected = format!("{:x}", lhs).cmp(&format!("{:x}", rhs));
+            assert_eq!(cmp_hex(lhs, rhs), expected, "hex");
+            assert_eq!(cmp_hex(rhs, lhs), expected.reverse(), "hex,reverse");
+            let expected = format!("{:o}", lhs).cmp(&format!("{:o}", rhs));
+            assert_eq!(cmp_oct(lhs, rhs), expected, "oct");
+            assert_eq!(cmp_oct(rhs, lhs), expected.reverse(), "oct,reverse");
+            let expected = format!("{:b}", lhs).cmp(&format!("{:b}", rhs));
+            assert_eq!(cmp_bin(lhs, rhs), expected, "bin");
+            assert_eq!(cmp_bin(rhs, lhs), expected.reverse(), "bin,reverse");
+        }
+
+        // Values around the digit boundaries of each radix.
+        let values = [
+            0_u64, 1, 2, 3, 4, 5, 7, 8, 9, 0xa, 0xf, 0x10, 0x11, 0x1f, 0x2a, 0x3f, 0x40, 0x41,
+            0x7f, 0x80, 0xa2, 0xff, 0x100, 0x1ff, 0x200, 0xfff, 0x1000,
+        ];
+        for &lhs in &values {
+            for &rhs in &values {
+                check(lhs, rhs);
+                check(lhs as i64, -(rhs as i64));
+                check(-(lhs as i64), -(rhs as i64));
+            }
+        }
+
+        // Works with min and max values.
+        check(u8::MAX, 1);
+        check(u8::MAX, u8::MAX - 1);
+        check(i8::MIN, -1);
+        check(i8::MIN, i8::MAX);
+        check(u64::MAX, 1);
+        check(u64::MAX, u64::MAX - 1);
+        check(i64::MIN, -1);
+        check(i64::MIN, i64::MAX);
+        check(u128::MAX, 1);
+        check(u128::MAX, u128::MAX - 1);
+        check(i128::MIN, -1);
+        check(i128::MIN, i128::MAX);
+    }
+
+    #[test]
+    fn radix_casing() {
+        use alloc::string::String;
+
+        fn to_radix(mut value: u64, radix: u32, uppercase: bool) -> String {
+            let mut ret = String::new();
+            loop {
+                let digit =
+                    std::char::from_digit((value % u64::from(radix)) as u32, radix).unwrap();
+                ret.insert(
+                    0,
+                    if uppercase {
+                        digit.to_ascii_uppercase()
+                    } else {
+                        digit
+                    },
+                );
+                value /= u64::from(radix);
+                if value == 0 {
+                    break ret;
+                }
+            }
+        }
+
+        #[track_caller]
+        fn check(lhs: u64, rhs: u64, radix: u32) {
+            let lower = to_radix(lhs, radix, false).cmp(&to_radix(rhs, radix, false));
+            let upper = to_radix(lhs, radix, true).cmp(&to_radix(rhs, radix, true));
+            assert_eq!(lower, upper, "{:?}", (lhs, rhs, radix));
+            assert_eq!(cmp_int(lhs, rhs, radix), lower, "{:?}", (lhs, rhs, radix));
+            if radix == 16 {
+                assert_eq!(format!("{:x}", lhs).cmp(&format!("{:x}", rhs)), lower);
+                assert_eq!(format!("{:X}", lhs).cmp(&format!("{:X}", rhs)), upper);
+                assert_eq!(cmp_hex(lhs, rhs), lower);
+            }
+        }
+
+        // Multi-digit values mixing decimal digits and letters at various positions.
+        let values = [
+            0x0,
+            0x9,
+            0xa,
+            0xf,
+            0x10,
+            0x19,
+            0x1a,
+            0x90,
+            0x99,
+            0x9a,
+            0x9f,
+            0xa0,
+            0xa9,
+            0xaa,
+            0xff,
+            0x100,
+            0x9a9,
+            0xa9a,
+            0xfff,
+            0x1000,
+            0x9999,
+            0xaaaa,
+            0xabcd,
+            0xdcba,
+            0xffff_ffff,
+        ];
+        for radix in 11..=36 {
+            for &lhs in &values {
+                for &rhs in &values {
+                    check(lhs, rhs, radix);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn signed_dec() {
+        use Ordering::{Equal, Greater, Less};
+
+        // Hand-verified against the string representations.
+        let table: &[(i32, i32, Ordering)] = &[
+            // Zero.
+            (0, 0, Equal),
+            (0, 1, Less),
+            (0, -1, Greater),
+            (0, 10, Less),
+            (0, -10, Greater),
+            // Mixed signs.
+            (-5, 5, Less),
+            (-5, 10, Less),
+            (-10, 5, Less),
+            (-1, 9, Less),
+            (7, -70, Greater),
+            // Equal magnitudes with opposite signs.
+            (-1, 1, Less),
+            (-42, 42, Less),
+            (i32::MAX, -i32::MAX, Greater),
+            // Both negative.
+            (-10, -5, Less),
+            (-50, -5, Greater),
+            (-5, -5, Equal),
+            (-42, -240, Greater),
+            (-100, -99, Less),
+            (i32::MIN, -1, Greater),
+            (i32::MIN, -3, Less),
+            // Both positive.
+            (10, 5, Less),
+            (42, 240, Greater),
+        ];
+
+        for &(x, y, expected) in table {
+            assert_eq!(
+                x.to_string().cmp(&y.to_string()),
+                expected,
+                "{}, {}, table",
+                x,
+                y
+            );
+            assert_eq!(cmp_signed_dec(x, y), expected, "{}, {}", x, y);
+            assert_eq!(
+                cmp_signed_dec(y, x),
+                expected.reverse(),
+                "{}, {}, reverse",
+                x,
+                y
+            );
+        }
+    }
+
+    #[test]
+    fn powers_of_ten() {
+        use alloc::vec::Vec;
+
+        macro_rules! check {
+            ($($ty:ident)*) => {$({
+                let mut values: Vec<$ty> = Vec::new();
+                let mut pow: $ty = 1;
+                loop {
+                    values.extend(pow.checked_sub(1));
+                    values.push(pow);
+                    values.extend(pow.checked_add(1));
+                    match pow.checked_mul(10) {
+                        Some(next) => pow = next,
+                        None => break,
+                    }
+                }
+                values.push($ty::max_value());
+                values.push($ty::max_value() - 1);
+                // Negate the values for signed types (no-op for unsigned types).
+                let negated: Vec<$ty> = values.iter().filter_map(|&v| (0 as $ty).checked_sub(v)).collect();
+                values.extend(negated);
+                values.push($ty::min_value());
+
+                for &x in &values {
+                    for &y in &values {
+                        let expected = x.to_string().cmp(&y.to_string());
+                        assert_eq!(cmp_dec(x, y), expected, "{}: {}, {}", stringify!($ty), x, y);
+                        assert_eq!(cmp_int(x, y, 10), expected, "{}: {}, {}, int", stringify!($ty), x, y);
+                    }
+                }
+            })*};
+        }
+
+        check! {
+            u8 u16 u32 u64 usize u128
+            i8 i16 i32 i64 isize i128
+        }
+    }
+
+    #[test]
+    fn same_digit_count() {
+        /// Panics if `div_pow` is called, which is unnecessary for numbers with the same number of
+        /// digits.
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        struct NoDivPow(u64);
+
+        impl Display for NoDivPow {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl FmtInteger for NoDivPow {
+            fn checked_log(&self, base: u32) -> Option<u32> {
+                self.0.checked_log(base)
+            }
+
+            fn div_pow(self, _: u32, _: u32) -> Self {
+                panic!("`div_pow` called with numbers of the same number of digits");
+            }
+        }
+
+        let mut pow = 1_u64;
+        for digits in 1..=19 {
+            let (lo, hi) = (pow, pow * 10 - 1);
+            let values = [
+                lo,
+                lo + 1,
+                (lo + 9).min(hi),
+                (lo + hi) / 2,
+                hi.saturating_sub(10).max(lo),
+                hi - 1,
+                hi,
+            ];
+            for &x in &values {
+                for &y in &values {
+                    let expected = x.to_string().cmp(&y.to_string());
+                    assert_eq!(cmp_dec(x, y), expected, "{}: {}, {}", digits, x, y);
+                    assert_eq!(cmp_int(x, y, 10), expected, "{}: {}, {}, int", digits, x, y);
+                    assert_eq!(
+                        cmp_dec(NoDivPow(x), NoDivPow(y)),
+                        expected,
+                        "{}: {}, {}, NoDivPow",
+                        digits,
+                        x,
+                        y
+                    );
+                    let (x, y) = (-(x as i128), -(y as i128));
+                    let expected = x.to_string().cmp(&y.to_string());
+                    assert_eq!(cmp_dec(x, y), expected, "{}: {}, {}, neg", digits, x, y);
+                }
+            }
+            pow = pow.saturating_mul(10);
+        }
+    }
+
+    #[test]
+    fn cmp_radix_matches_cmp_int() {
+        struct Unary;
+        impl Radix for Unary {
+            const RADIX: u32 = 1;
+        }
+
+        struct Base36;
+        impl Radix for Base36 {
+            const RADIX: u32 = 36;
+        }
+
+        struct Huge;
+        impl Radix for Huge {
+            const RADIX: u32 = u32::max_value();
+        }
+
+        #[track_caller]
+        fn check<T: Integer + Copy + Debug>(x: T, y: T) {
+            macro_rules! check {
+                ($($r:ty)*) => {$(
+                    assert_eq!(
+                        cmp_radix::<$r, T>(x, y),
+                        cmp_int(x, y, <$r>::RADIX),
+                        "{:?}, {:?}, {}",
+                        x,
+                        y,
+                        stringify!($r),
+                    );
+                )*};
+            }
+            check!(Unary Bin Oct Dec Hex Base36 Huge);
+        }
+
+        let values = [
+            0, 1, 2, 7, 8, 9, 10, 15, 16, 35, 36, 42, 99, 240, 255, 256, 1000,
+        ];
+        for &x in &values {
+            for &y in &values {
+                check::<u32>(x, y);
+                check::<i64>(x.into(), -i64::from(y));
+                check::<i64>(-i64::from(x), -i64::from(y));
+            }
+            check(u128::from(x), u128::max_value());
+            check(i8::min_value(), x as i8);
+        }
+    }
+
+    #[test]
+    fn unary() {
+        #[track_caller]
+        fn check<T: Copy + Debug + Integer + Ord>(lhs: T, rhs: T) {
+            assert_eq!(cmp_int(lhs, rhs, 1), lhs.cmp(&rhs));
+            assert_eq!(cmp_int(rhs, lhs, 1), rhs.cmp(&lhs), "reverse");
+        }
+
+        for &x in &[
+            0,
+            1,
+            2,
+            9,
+            10,
+            u128::from(u64::MAX),
+            u128::MAX - 1,
+            u128::MAX,
+        ] {
+            for &y in &[0, 1, 10, u128::MAX >> 1, u128::MAX] {
+                check(x, y);
+            }
+        }
+        check(u8::MAX, 0);
+        check(u8::MAX, u8::MAX - 1);
+        check(u16::MAX, 1);
+        check(u32::MAX, 1);
+        check(u64::MAX, 0);
+        check(u64::MAX, u64::MAX - 1);
+        check(usize::MAX, 1);
+        check(i128::MAX, 0);
+        check(i128::MAX, i128::MAX - 1);
+
+        // Negative numbers are compared in the sign-magnitude representation.
+        assert_eq!(cmp_int(-1_i32, 0, 1), Ordering::Less);
+        assert_eq!(cmp_int(-2_i32, -1, 1), Ordering::Greater);
+        assert_eq!(cmp_int(i128::MIN, -1, 1), Ordering::Greater);
+        assert_eq!(cmp_int(i128::MIN, i128::MAX, 1), Ordering::Less);
+    }
+
+    #[test]
+    fn div_pow_base_1() {
+        use super::traits::private::Unsigned;
+
+        assert_eq!(u128::MAX.div_pow(1, 0), u128::MAX);
+        assert_eq!(u128::MAX.div_pow(1, 1), u128::MAX);
+        assert_eq!(u128::MAX.div_pow(1, u32::max_value()), u128::MAX);
+        assert_eq!(Unsigned::invpow(u128::MAX, 1, 0), u128::MAX);
+        assert_eq!(Unsigned::invpow(u128::MAX, 1, 127), u128::MAX);
+        assert_eq!(Unsigned::invpow(u8::MAX, 1, u32::max_value()), u8::MAX);
+        assert_eq!(Unsigned::invpow(42_u64, 1, 64), 42);
+    }
+
+    #[test]
+    fn matches_digit_values_cmp() {
+        use alloc::vec::Vec;
+
+        /// Returns the digit values of `value` from the most significant digit.
+        fn digits(mut value: u128, radix: u32) -> Vec<u128> {
+            let radix = u128::from(radix);
+            let mut ret = Vec::new();
+            loop {
+                ret.push(value % radix);
+                value /= radix;
+                if value == 0 {
+                    break;
+                }
+            }
+            ret.reverse();
+            ret
+        }
+
+        #[track_caller]
+        fn check(lhs: u64, rhs: u64, radix: u32) {
+            let (l, r) = (u128::from(lhs), u128::from(rhs));
+            let expected = digits(l, radix).cmp(&digits(r, radix));
+            assert_eq!(cmp_int(lhs, rhs, radix), expected, "{}", radix);
+            assert_eq!(
+                cmp_int(rhs, lhs, radix),
+                expected.reverse(),
+                "{},reverse",
+                radix
+            );
+            assert_eq!(cmp_int(l, r, radix), expected, "{},u128", radix);
+            if 0 < lhs && lhs <= i64::MAX as u64 && 0 < rhs && rhs <= i64::MAX as u64 {
+                let (l, r) = (-(lhs as i64), -(rhs as i64));
+                assert_eq!(cmp_int(l, r, radix), expected, "{},negative", radix);
+                assert!(cmp_int(l, rhs as i64, radix).is_lt(), "{},sign", radix);
+            }
+            if lhs <= u64::from(u8::MAX) && rhs <= u64::from(u8::MAX) {
+                let (l, r) = (lhs as u8, rhs as u8);
+                assert_eq!(cmp_int(l, r, radix), expected, "{},u8", radix);
+            }
+        }
+
+        let radixes = [
+            2,
+            3,
+            7,
+            10,
+            16,
+            36,
+            37,
+            62,
+            64,
+            100,
+            128,
+            255,
+            256,
+            257,
+            1000,
+            65_536,
+            u32::MAX,
+        ];
+        for &radix in &radixes {
+            let r = u64::from(radix);
+            // Values around the digit boundaries (saturating for large radixes).
+            let rr = r.saturating_mul(r);
+            let values = [
+                0,
+                1,
+                2,
+                r - 1,
+                r,
+                r + 1,
+                2 * r - 1,
+                2 * r,
+                rr - 1,
+                rr,
+                rr.saturating_add(1),
+                rr.saturating_add(r),
+                rr.saturating_mul(r - 1),
+                u64::MAX / 2,
+                u64::MAX - 1,
+                u64::MAX,
+            ];
+            for &lhs in &values {
+                for &rhs in &values {
+                    check(lhs, rhs, radix);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cmp_dec_mixed_matches_str_cmp() {
+        #[track_caller]
+        fn check<T, U>(lhs: T, rhs: U)
+        where
+            T: Copy + Display + PrimitiveInteger,
+            U: Copy + Display + PrimitiveInteger,
+        {
+            let expected = lhs.to_string().cmp(&rhs.to_string());
+            assert_eq!(cmp_dec_mixed(lhs, rhs), expected);
+            assert_eq!(cmp_dec_mixed(rhs, lhs), expected.reverse(), "reverse");
+        }
+
+        let values = [
+            0_u64,
+            1,
+            2,
+            25,
+            26,
+            254,
+            255,
+            256,
+            2550,
+            2551,
+            25_500,
+            65_535,
+            65_536,
+            655_350,
+            u64::from(u32::MAX),
+            u64::MAX,
+        ];
+        for &value in &values {
+            check(u8::MAX, value);
+            check(u8::MAX - 1, value);
+            check(i8::MIN, value);
+            check(i8::MAX, value);
+            check(u16::MAX, value as u128);
+            check(u16::MAX, u128::MAX - value as u128);
+            check(i16::MIN, value as i128);
+            check(i16::MIN, -(value as i128));
+            check(u128::MAX, value as u16);
+            check(u128::MAX, value as i16);
+            check(i128::MIN, value as u16);
+            check(i128::MIN, value as i16);
+            check(value as i64, -(value as i128));
+            check(value as usize, value as u128);
+        }
+    }
+
+    #[test]
+    fn cmp_dec_padded_matches_str_cmp() {
+        #[track_caller]
+        fn check<T: Copy + Display + Integer>(lhs: T, rhs: T, width: usize) {
+            let expected =
+                format!("{:0w$}", lhs, w = width).cmp(&format!("{:0w$}", rhs, w = width));
+            assert_eq!(cmp_dec_padded(lhs, rhs, width), expected, "{}", width);
+            assert_eq!(
+                cmp_dec_padded(rhs, lhs, width),
+                expected.reverse(),
+                "{},reverse",
+                width
+            );
+        }
+
+        // Values straddling the width boundaries.
+        let values = [
+            0_i64, 1, 2, 9, 10, 12, 42, 99, 100, 123, 240, 420, 999, 1000, 1234, 4200, 9999,
+            10_000, 12_345, 42_000, 99_999, 100_000, 123_456,
+        ];
+        for width in 0..=7 {
+            for &lhs in &values {
+                for &rhs in &values {
+                    check(lhs as u64, rhs as u64, width);
+                    check(lhs, rhs, width);
+                    check(-lhs, rhs, width);
+                    check(lhs, -rhs, width);
+                    check(-lhs, -rhs, width);
+                }
+            }
+        }
+
+        // Works with min and max values.
+        for &width in &[0, 1, 20, 40, usize::MAX] {
+            check(u64::MAX, 1, width.min(64));
+            check(i64::MIN, -1, width.min(64));
+            check(i64::MIN, i64::MAX, width.min(64));
+            check(u128::MAX, u128::MAX - 1, width.min(64));
+            assert!(cmp_dec_padded(u128::MAX, 1, width).is_gt());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sort_dec_matches_str_sort() {
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        #[track_caller]
+        fn check<T: Copy + Debug + Display + Integer + Ord>(values: &[T]) {
+            let mut expected: Vec<(String, T)> =
+                values.iter().map(|&v| (v.to_string(), v)).collect();
+            expected.sort();
+            let expected: Vec<T> = expected.into_iter().map(|(_, v)| v).collect();
+
+            let mut actual = values.to_vec();
+            sort_dec(&mut actual);
+            assert_eq!(actual, expected);
+        }
+
+        // Pseudo-random values with various numbers of digits.
+        let mut x = 0x2545_f491_4f6c_dd1d_u64;
+        let values: Vec<u64> = (0..1000)
+            .map(|i| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                x >> (i % 64)
+            })
+            .collect();
+        check(&values);
+        check(&values.iter().map(|&v| v as i64).collect::<Vec<_>>());
+        check(&values.iter().map(|&v| v as u8).collect::<Vec<_>>());
+        check(&[0_u32, 10, 1, 100, 0, 9, 99, 90, 1000, 10]);
+        check(&[
+            0_i32,
+            -10,
+            1,
+            -100,
+            0,
+            -9,
+            99,
+            -90,
+            1000,
+            -10,
+            i32::MIN,
+            i32::MAX,
+        ]);
+        check::<u32>(&[]);
+    }
+
+    #[test]
+    fn num_digits_matches_str_len() {
+        #[track_caller]
+        fn check<T: Copy + Integer + ToString>(value: T) {
+            let expected = value.to_string().trim_start_matches('-').len() as u32;
+            assert_eq!(num_digits(value, 10), expected);
+        }
+
+        check(0_u8);
+        check(9_u8);
+        check(10_u8);
+        check(0_i8);
+        check(-9_i8);
+        check(-10_i8);
+
+        check(u8::MAX);
+        check(u16::MAX);
+        check(u32::MAX);
+        check(u64::MAX);
+        check(u128::MAX);
+        check(usize::MAX);
+        check(i8::MAX);
+        check(i16::MAX);
+        check(i32::MAX);
+        check(i64::MAX);
+        check(i128::MAX);
+        check(isize::MAX);
+        check(i8::MIN);
+        check(i16::MIN);
+        check(i32::MIN);
+        check(i64::MIN);
+        check(i128::MIN);
+        check(isize::MIN);
+    }
+
+    #[test]
+    fn num_digits_radix() {
+        assert_eq!(num_digits(0_u32, 2), 1);
+        assert_eq!(num_digits(1_u32, 2), 1);
+        assert_eq!(num_digits(2_u32, 2), 2);
+        assert_eq!(num_digits(u32::MAX, 2), 32);
+        assert_eq!(num_digits(u32::MAX, 16), 8);
+        assert_eq!(num_digits(u128::MAX, 16), 32);
+        assert_eq!(num_digits(0o777_u32, 8), 3);
+        assert_eq!(num_digits(0o1000_u32, 8), 4);
+
+        // Unary system.
+        assert_eq!(num_digits(0_u32, 1), 1);
+        assert_eq!(num_digits(u128::MAX, 1), 1);
+
+        // Radixes greater than `T::MAX`.
+        assert_eq!(num_digits(u8::MAX, 256), 1);
+        assert_eq!(num_digits(u8::MAX, 300), 1);
+        assert_eq!(num_digits(u16::MAX, 0x1_0000), 1);
+        assert_eq!(num_digits(u16::MAX, 0xff), 3);
+    }
+
+    #[test]
+    fn cmp_int_alphabet_matches_naive() {
+        use alloc::vec::Vec;
+
+        const BASE58: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+        const REVERSED: &[u8] = b"ZYXWVUTSRQ";
+        // Places a glyph before `'-'`.
+        const SHUFFLED: &[u8] = b"z!a";
+
+        fn naive(value: i128, alphabet: &[u8]) -> Vec<u8> {
+            let base = alphabet.len() as u128;
+            let mut abs = value.unsigned_abs();
+            let mut ret = Vec::new();
+            loop {
+                ret.push(alphabet[(abs % base) as usize]);
+                abs /= base;
+                if abs == 0 {
+                    break;
+                }
+            }
+            if value < 0 {
+                ret.push(b'-');
+            }
+            ret.reverse();
+            ret
+        }
+
+        #[track_caller]
+        fn check<T: PrimitiveInteger + Copy + Into<i128>>(lhs: T, rhs: T) {
+            for &alphabet in &[BASE58, REVERSED, SHUFFLED, b"01", b"0123456789"] {
+                let expected = naive(lhs.into(), alphabet).cmp(&naive(rhs.into(), alphabet));
+                let alphabet_str = core::str::from_utf8(alphabet).unwrap();
+                assert_eq!(
+                    cmp_int_alphabet(lhs, rhs, alphabet),
+                    expected,
+                    "{}",
+                    alphabet_str
+                );
+                assert_eq!(
+                    cmp_int_alphabet(rhs, lhs, alphabet),
+                    expected.reverse(),
+                    "{},rev",
+                    alphabet_str
+                );
+            }
+            if lhs.into() >= 0 && rhs.into() >= 0 {
+                assert_eq!(
+                    cmp_int_alphabet(lhs, rhs, b"0123456789"),
+                    cmp_dec(lhs.into(), rhs.into()),
+                    "dec"
+                );
+            }
+        }
+
+        let values = [0, 1, 2, 9, 10, 11, 42, 57, 58, 59, 240, 3363, 3364, 100_000];
+        for &x in &values {
+            for &y in &values {
+                check::<u32>(x, y);
+                check::<i64>(x.into(), -i64::from(y));
+                check::<i64>(-i64::from(x), -i64::from(y));
+            }
+        }
+        check(u64::max_value(), 0);
+        check(u64::max_value(), u64::max_value() - 1);
+        check(i64::min_value(), i64::max_value());
+        check(i64::min_value(), -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "`alphabet` must have at least 2 digits")]
+    fn cmp_int_alphabet_unary() {
+        let _ = cmp_int_alphabet(42_u32, 240, b"0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn num_digits_radix_zero() {
+        let _ = num_digits(42_u32, 0);
+    }
+
+    #[test]
+    fn fmt_integer() {
+        /// A 256-bit unsigned integer with the most significant limb first.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct U256([u64; 4]);
+
+        impl U256 {
+            fn from_u64(x: u64) -> Self {
+                U256([0, 0, 0, x])
+            }
+
+            /// Divides `self` by `divisor` and returns the remainder.
+            fn div_rem(&mut self, divisor: u64) -> u64 {
+                let mut rem = 0_u128;
+                for limb in &mut self.0 {
+                    let x = (rem << 64) | u128::from(*limb);
+                    *limb = (x / u128::from(divisor)) as u64;
+                    rem = x % u128::from(divisor);
+                }
+                rem as u64
+            }
+        }
+
+        impl Display for U256 {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let mut buf = [0_u8; 78];
+                let mut pos = buf.len();
+                let mut x = *self;
+                loop {
+                    pos -= 1;
+                    buf[pos] = b'0' + x.div_rem(10) as u8;
+                    if x == U256::from_u64(0) {
+                        break;
+                    }
+                }
+                f.write_str(std::str::from_utf8(&buf[pos..]).unwrap())
+            }
+        }
+
+        impl FmtInteger for U256 {
+            fn checked_log(&self, base: u32) -> Option<u32> {
+                if *self == U256::from_u64(0) {
+                    return None;
+                }
+                let base = u64::from(base);
+                let mut x = *self;
+                let mut ret = 0;
+                while x >= U256::from_u64(base) {
+                    x.div_rem(base);
+                    ret += 1;
+                }
+                Some(ret)
+            }
+
+            fn div_pow(mut self, base: u32, exp: u32) -> Self {
+                for _ in 0..exp {
+                    self.div_rem(base.into());
+                }
+                self
+            }
+        }
+
+        #[track_caller]
+        fn check(lhs: U256, rhs: U256) {
+            let expected = lhs.to_string().cmp(&rhs.to_string());
+            assert_eq!(cmp_int(lhs, rhs, 10), expected);
+            assert_eq!(cmp_int(rhs, lhs, 10), expected.reverse(), "reverse");
+            assert_eq!(cmp_dec(lhs, rhs), expected, "dec");
+            assert_eq!(cmp_dec(rhs, lhs), expected.reverse(), "dec,reverse");
+            assert_eq!(cmp_int(lhs, rhs, 1), lhs.cmp(&rhs), "unary");
+        }
+
+        let values = [
+            U256::from_u64(0),
+            U256::from_u64(2),
+            U256::from_u64(42),
+            U256::from_u64(240),
+            U256::from_u64(u64::MAX),
+            U256([0, 0, 1, 0]),
+            U256([0, 0, 42, 0]),
+            U256([0, 1, 0, 0]),
+            U256([1, 0, 0, 0]),
+            U256([42, 0, 0, 42]),
+            U256([u64::MAX; 4]),
+        ];
+        for &lhs in &values {
+            for &rhs in &values {
+                check(lhs, rhs);
+            }
+        }
+
+        assert_eq!(num_digits(U256([u64::MAX; 4]), 10), 78);
+        assert_eq!(num_digits(U256([u64::MAX; 4]), 16), 64);
+    }
 }
@@ -0,0 +1,46 @@
+/// A radix known at compile time, for [`cmp_radix`](super::cmp_radix) function.
+///
+/// This crate provides the implementors for the common radixes: [`Bin`], [`Oct`], [`Dec`] and
+/// [`Hex`]. You can implement this trait on your own type for other radixes.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::{cmp_radix, Radix};
+///
+/// struct Base36;
+///
+/// impl Radix for Base36 {
+///     const RADIX: u32 = 36;
+/// }
+///
+/// // `"z" > "10"`
+/// assert!(cmp_radix::<Base36, u32>(35, 36).is_gt());
+/// ```
+pub trait Radix {
+    /// The radix, which must not be zero.
+    const RADIX: u32;
+}
+
+macro_rules! radix {
+    ($($(#[$attr:meta])* $name:ident = $radix:expr;)*) => {$(
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name;
+
+        impl Radix for $name {
+            const RADIX: u32 = $radix;
+        }
+    )*};
+}
+
+radix! {
+    /// The binary radix.
+    Bin = 2;
+    /// The octal radix.
+    Oct = 8;
+    /// The decimal radix.
+    Dec = 10;
+    /// The hexadecimal radix.
+    Hex = 16;
+}
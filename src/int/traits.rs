@@ -3,43 +3,140 @@
 
 /// A trait for integer types that can be compared with [`cmp_int`](super::cmp_int) function.
 ///
-/// This trait is sealed and cannot be implemented outside of `fmt_cmp` crate.
+/// This trait is implemented for the integer primitives and every type implementing
+/// [`FmtInteger`]. Other than that, this trait is sealed and cannot be implemented outside of
+/// `fmt_cmp` crate.
 pub trait Integer: private::Sealed {}
 
-mod private {
+/// A trait for non-negative integer types that can be compared with [`cmp_int`](super::cmp_int)
+/// function.
+///
+/// Unlike [`Integer`], this trait is not sealed, so that you can implement this for third-party
+/// integer types like big integers. Every type implementing this trait implements [`Integer`].
+///
+/// This is implemented for the unsigned integer primitives.
+///
+/// ## Contract
+///
+/// The comparison functions assume that the following properties hold, and would return
+/// unspecified results otherwise:
+///
+/// - `Ord` implementation of `Self` is consistent with the numerical ordering of the values.
+/// - The methods behave as described by their documentation.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::int::FmtInteger;
+///
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// struct MyInt(u64);
+///
+/// impl FmtInteger for MyInt {
+///     fn checked_log(&self, base: u32) -> Option<u32> {
+///         self.0.checked_ilog(base.into())
+///     }
+///
+///     fn div_pow(self, base: u32, exp: u32) -> Self {
+///         MyInt(self.0 / u64::from(base).pow(exp))
+///     }
+/// }
+///
+/// assert!(fmt_cmp::cmp_dec(MyInt(42), MyInt(240)).is_gt());
+/// ```
+pub trait FmtInteger: Ord {
+    /// Returns the logarithm of `self` with respect to `base`, rounded down, or `None` if `self`
+    /// is zero.
+    ///
+    /// In other words, this returns the number of digits of `self` in radix `base`, minus one.
+    ///
+    /// The callers in this crate never call this with `base < 2`.
+    fn checked_log(&self, base: u32) -> Option<u32>;
+
+    /// Returns the base 10 logarithm of `self`, rounded down, or `None` if `self` is zero.
+    ///
+    /// This must return the same result as `self.checked_log(10)`. Implementors may override this
+    /// to provide a faster implementation.
+    fn checked_log10(&self) -> Option<u32> {
+        self.checked_log(10)
+    }
+
+    /// Returns `self / base.pow(exp)`, rounded down.
+    ///
+    /// The callers in this crate never call this with `base < 2` or with `exp` greater than
+    /// `self.checked_log(base).unwrap_or(0)`, so `base.pow(exp)` never exceeds `self`.
+    fn div_pow(self, base: u32, exp: u32) -> Self;
+}
+
+/// A trait for the integer primitives, which can be compared with each other with
+/// [`cmp_dec_mixed`](super::cmp_dec_mixed) function.
+///
+/// This trait is sealed and cannot be implemented outside of `fmt_cmp` crate.
+pub trait PrimitiveInteger: Integer + private::Primitive {}
+
+/// A trait for the signed integer primitives, which can be compared with
+/// [`cmp_signed_dec`](super::cmp_signed_dec) function.
+///
+/// This trait is sealed and cannot be implemented outside of `fmt_cmp` crate.
+pub trait SignedInteger: PrimitiveInteger {}
+
+pub(super) mod private {
     pub trait Sealed {
-        fn copy(&self) -> Self;
-        fn eq(self, other: Self) -> bool;
-        fn lt(self, other: Self) -> bool;
+        /// The unsigned integer type that can represent the absolute value of `Self`.
+        type Unsigned: super::Integer + super::FmtInteger;
+        /// Returns whether `self` is negative and the absolute value of `self`.
+        fn split_sign(self) -> (bool, Self::Unsigned);
+        /// Reinterprets the two's complement representation of `self` as `Self::Unsigned`.
+        fn to_unsigned(self) -> Self::Unsigned;
+    }
+
+    pub trait Primitive {
+        /// Returns whether `self` is negative and the absolute value of `self` as `u128`.
+        fn split_sign_u128(self) -> (bool, u128);
+        /// Calculates `self / base.pow(exp)` without overflow, rounding toward zero.
+        /// `base` must not be zero.
+        fn div_pow(self, base: u32, exp: u32) -> Self;
+    }
+
+    pub trait Unsigned {
+        // These are shadowed by the inherent methods of the same names if available.
+        #[allow(dead_code)]
         fn checked_ilog(self, base: Self) -> Option<u32>;
         fn ilog(self, base: u32) -> u32;
+        #[allow(dead_code)]
         fn checked_ilog10(self) -> Option<u32>;
         fn ilog10(self) -> u32;
         /// Calculates `self / base.pow(exp)`.
+        ///
+        /// `base` must not be zero and `base.pow(exp)` must not exceed `Self::MAX`. In particular,
+        /// `base == 1` is allowed with any `exp`, though the callers never pass a nonzero `exp`
+        /// with `base == 1` because `ilog(1)` is always zero.
         fn invpow(self, base: u32, exp: u32) -> Self;
     }
 }
 
-macro_rules! sealed_common {
-    () => {
-        fn copy(&self) -> Self {
-            *self
-        }
-
-        fn eq(self, other: Self) -> bool {
-            self == other
-        }
-
-        fn lt(self, other: Self) -> bool {
-            self < other
-        }
+/// Asserts a condition on constants at compile time.
+///
+/// `assert!` with constant operands is folded away by the optimizer anyway, but this makes sure
+/// that it costs nothing in unoptimized builds either and that a violation fails the build rather
+/// than panicking at runtime. (`const` panics are not available on our MSRV, so this uses the
+/// array-length trick instead.)
+macro_rules! const_assert {
+    ($cond:expr) => {
+        const _: [(); 0 - !{ $cond } as usize] = [];
+    };
+}
 
+macro_rules! unsigned_common {
+    () => {
         fn checked_ilog(mut self, base: Self) -> Option<u32> {
             // Well, the function isn't _checking_ anything in fact, since we are not going to call
             // it with `base == 0` and defaulting to `0` if `None` is returned.
             // It is returning `Option<_>` only for consistency with the inherent `checked_ilog`.
             if base <= 1 {
-                assert!(base > 0);
+                // The radix is validated before reaching here, and the inherent `checked_ilog`
+                // doesn't panic on `base == 0` either, so this needn't be a hard check.
+                debug_assert!(base > 0);
                 return Some(0);
             }
             let mut x = 0;
@@ -54,6 +151,10 @@ macro_rules! sealed_common {
         // `checked_ilog` if available or uses the fallback impl otherwise.
         #[allow(unstable_name_collisions)]
         fn ilog(self, base: u32) -> u32 {
+            if base as Self as u32 != base {
+                // `base` is greater than `Self::MAX` so every value of `Self` has only one digit.
+                return 0;
+            }
             if let Some(x) = self.checked_ilog(base as _) {
                 x
             } else {
@@ -79,6 +180,12 @@ macro_rules! sealed_common {
             if exp == 0 {
                 return self;
             }
+            debug_assert_ne!(base, 0, "`invpow` called with `base == 0`");
+            if base == 1 {
+                // The loop below would also return `self` unchanged, since `1 * 1` never
+                // overflows, but there is no point in running it.
+                return self;
+            }
             // The `exp` argument in our use case is `Self.ilog(base) - Self.ilog(base)`,
             // which would be zero if `base > Self::MAX` so the `as` conversion is lossless.
             let mut base = base as Self;
@@ -99,8 +206,8 @@ macro_rules! sealed_common {
 // These specialized `ilog10` implementations are based on `core`'s ones.
 // <https://doc.rust-lang.org/1.80.0/src/core/num/int_log10.rs.html#52-92>
 
-impl private::Sealed for u32 {
-    sealed_common!();
+impl private::Unsigned for u32 {
+    unsigned_common!();
 
     #[allow(unstable_name_collisions)]
     fn checked_ilog10(mut self) -> Option<u32> {
@@ -112,15 +219,15 @@ impl private::Sealed for u32 {
         };
 
         // Checking that `self` would be `<= u16::MAX` now even if the argument were `u32::MAX`...
-        assert!((!0_u32) / 100_000 <= (!0_u16) as u32);
+        const_assert!((!0_u32) / 100_000 <= (!0_u16) as u32);
         debug_assert!(self <= (!0_u16) as u32); // ... so that this holds.
 
         Some((self as u16).ilog(10) + x)
     }
 }
 
-impl private::Sealed for u64 {
-    sealed_common!();
+impl private::Unsigned for u64 {
+    unsigned_common!();
 
     #[allow(unstable_name_collisions)]
     fn checked_ilog10(mut self) -> Option<u32> {
@@ -130,20 +237,22 @@ impl private::Sealed for u64 {
         } else {
             0
         };
-        assert!((!0_u64) / 10_000_000_000 <= (!0_u32) as u64);
+        const_assert!((!0_u64) / 10_000_000_000 <= (!0_u32) as u64);
         debug_assert!(self <= (!0_u32) as u64);
         Some((self as u32).ilog(10) + x)
     }
 }
 
-impl private::Sealed for u128 {
-    sealed_common!();
+impl private::Unsigned for u128 {
+    unsigned_common!();
 
     #[allow(unstable_name_collisions)]
     fn checked_ilog10(mut self) -> Option<u32> {
         if self >= 100_000_000_000_000_000_000_000_000_000_000 {
             self /= 100_000_000_000_000_000_000_000_000_000_000;
-            assert!((!0_u128) / 100_000_000_000_000_000_000_000_000_000_000 <= (!0_u32) as u128);
+            const_assert!(
+                (!0_u128) / 100_000_000_000_000_000_000_000_000_000_000 <= (!0_u32) as u128
+            );
             debug_assert!(self <= (!0_u32) as u128);
             return Some((self as u32).ilog(10) + 32);
         }
@@ -153,7 +262,7 @@ impl private::Sealed for u128 {
         } else {
             0
         };
-        assert!(
+        const_assert!(
             (100_000_000_000_000_000_000_000_000_000_000 - 1) / 10_000_000_000_000_000
                 <= (!0_u64) as u128
         );
@@ -164,8 +273,8 @@ impl private::Sealed for u128 {
 
 macro_rules! generic_ilog10 {
     ($($ty:ty)*) => {$(
-        impl private::Sealed for $ty {
-            sealed_common!();
+        impl private::Unsigned for $ty {
+            unsigned_common!();
 
             #[allow(unstable_name_collisions)]
             fn checked_ilog10(self) -> Option<u32> {
@@ -178,8 +287,8 @@ macro_rules! generic_ilog10 {
 generic_ilog10! { u8 u16 }
 
 #[cfg(target_pointer_width = "64")]
-impl private::Sealed for usize {
-    sealed_common!();
+impl private::Unsigned for usize {
+    unsigned_common!();
     #[allow(unstable_name_collisions)]
     fn checked_ilog10(self) -> Option<u32> {
         (self as u64).checked_ilog10()
@@ -187,8 +296,8 @@ impl private::Sealed for usize {
 }
 
 #[cfg(target_pointer_width = "32")]
-impl private::Sealed for usize {
-    sealed_common!();
+impl private::Unsigned for usize {
+    unsigned_common!();
     #[allow(unstable_name_collisions)]
     fn checked_ilog10(self) -> Option<u32> {
         (self as u32).checked_ilog10()
@@ -198,9 +307,118 @@ impl private::Sealed for usize {
 #[cfg(not(any(target_pointer_width = "64", target_pointer_width = "32")))]
 generic_ilog10! { usize }
 
-impl Integer for u8 {}
-impl Integer for u16 {}
-impl Integer for u32 {}
-impl Integer for u64 {}
-impl Integer for u128 {}
-impl Integer for usize {}
+impl<T: FmtInteger> private::Sealed for T {
+    type Unsigned = Self;
+
+    fn split_sign(self) -> (bool, Self) {
+        (false, self)
+    }
+
+    fn to_unsigned(self) -> Self {
+        self
+    }
+}
+
+impl<T: FmtInteger> Integer for T {}
+
+macro_rules! unsigned {
+    ($($ty:ty)*) => {$(
+        impl FmtInteger for $ty {
+            fn checked_log(&self, base: u32) -> Option<u32> {
+                if *self == 0 {
+                    None
+                } else {
+                    Some(private::Unsigned::ilog(*self, base))
+                }
+            }
+
+            fn checked_log10(&self) -> Option<u32> {
+                if *self == 0 {
+                    None
+                } else {
+                    Some(private::Unsigned::ilog10(*self))
+                }
+            }
+
+            fn div_pow(self, base: u32, exp: u32) -> Self {
+                private::Unsigned::invpow(self, base, exp)
+            }
+        }
+
+        impl private::Primitive for $ty {
+            fn split_sign_u128(self) -> (bool, u128) {
+                (false, self as u128)
+            }
+
+            fn div_pow(self, base: u32, exp: u32) -> Self {
+                if base == 1 {
+                    return self;
+                }
+                // `invpow` requires `base.pow(exp) <= self`.
+                if exp > private::Unsigned::ilog(self, base) {
+                    return 0;
+                }
+                private::Unsigned::invpow(self, base, exp)
+            }
+        }
+
+        impl PrimitiveInteger for $ty {}
+    )*};
+}
+
+unsigned! { u8 u16 u32 u64 u128 usize }
+
+macro_rules! signed {
+    ($($ty:ty => $unsigned:ty,)*) => {$(
+        impl private::Sealed for $ty {
+            type Unsigned = $unsigned;
+
+            fn split_sign(self) -> (bool, $unsigned) {
+                // `wrapping_neg` is required for `Self::MIN`, whose absolute value does not fit
+                // in `Self`.
+                if self < 0 {
+                    (true, (self as $unsigned).wrapping_neg())
+                } else {
+                    (false, self as $unsigned)
+                }
+            }
+
+            fn to_unsigned(self) -> $unsigned {
+                self as $unsigned
+            }
+        }
+
+        impl Integer for $ty {}
+
+        impl private::Primitive for $ty {
+            fn split_sign_u128(self) -> (bool, u128) {
+                let (is_negative, abs) = private::Sealed::split_sign(self);
+                (is_negative, abs as u128)
+            }
+
+            fn div_pow(self, base: u32, exp: u32) -> Self {
+                let (is_negative, abs) = private::Sealed::split_sign(self);
+                // This doesn't overflow even for `Self::MIN`, whose absolute value wraps around to
+                // `Self::MIN` again.
+                let ret = private::Primitive::div_pow(abs, base, exp) as $ty;
+                if is_negative {
+                    ret.wrapping_neg()
+                } else {
+                    ret
+                }
+            }
+        }
+
+        impl PrimitiveInteger for $ty {}
+        impl SignedInteger for $ty {}
+    )*};
+}
+
+signed! {
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+}
@@ -17,9 +17,22 @@ extern crate std as alloc;
 
 pub mod cmp;
 pub mod int;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+pub mod util;
 
+#[cfg(test)]
+mod testing;
 mod traits;
 
-pub use self::cmp::{cmp, eq, hash, Cmp};
-pub use self::int::{cmp_dec, cmp_int};
-pub use self::traits::{FmtEq, FmtOrd};
+pub use self::cmp::{
+    by_display, cmp, cmp_at, common_prefix_len, eq, hash, sort_slice, try_cmp, try_eq, Cmp,
+};
+#[cfg(feature = "alloc")]
+pub use self::cmp::{by_display_key, insert_sorted_by_display, sorted};
+pub use self::int::{cmp_bin, cmp_dec, cmp_hex, cmp_int, cmp_oct};
+pub use self::traits::{DisplayCompareExt, FmtEq, FmtOrd};
+
+/// Derive macros for [`FmtEq`] and [`FmtOrd`] traits.
+#[cfg(feature = "derive")]
+pub use fmt_cmp_derive::{FmtEq, FmtOrd};
@@ -0,0 +1,163 @@
+//! Utilities for testing [`FmtEq`] and [`FmtOrd`] implementations.
+//!
+//! The marker traits cannot be checked by the compiler, so an implementation that doesn't agree
+//! with the `Display` implementation goes unnoticed until `Cmp` or a `BTreeMap` misbehaves. The
+//! functions in this module check the contracts over a set of sample values, which is meant to be
+//! called from the unit tests of the crates implementing the traits.
+//!
+//! This module is available with `test-util` feature.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::fmt::{self, Display, Formatter};
+//!
+//! #[derive(PartialEq, Eq, PartialOrd, Ord)]
+//! struct Name(&'static str);
+//!
+//! impl Display for Name {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+//!         f.write_str(self.0)
+//!     }
+//! }
+//!
+//! impl fmt_cmp::FmtEq for Name {}
+//! impl fmt_cmp::FmtOrd for Name {}
+//!
+//! fmt_cmp::test_util::assert_fmt_ord_contract(&[Name(""), Name("a"), Name("ab"), Name("b")]);
+//! ```
+
+use crate::cmp::{generic_cmp, generic_eq};
+use crate::{FmtEq, FmtOrd};
+
+/// Asserts that every pair of `samples` satisfies the [`FmtEq`] contract, i.e., `a == b` if and
+/// only if `a` and `b` have the same `Display` representation.
+///
+/// The `Display` representations are always compared by formatting the values, so that the
+/// shortcuts for primitive types don't make the check vacuous.
+///
+/// ## Panics
+///
+/// Panics if any pair of `samples` (including a sample and itself) violates the contract.
+///
+/// ## Example
+///
+/// Floating-point numbers would violate the contract:
+///
+/// ```should_panic
+/// # use std::fmt::{self, Display, Formatter};
+/// #
+/// #[derive(PartialEq)]
+/// struct Float(f64);
+///
+/// impl Eq for Float {}
+/// impl fmt_cmp::FmtEq for Float {}
+/// # impl Display for Float {
+/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// #         self.0.fmt(f)
+/// #     }
+/// # }
+///
+/// // `0.0 == -0.0` but `"0" != "-0"`.
+/// fmt_cmp::test_util::assert_fmt_eq_contract(&[Float(0.0), Float(-0.0)]);
+/// ```
+pub fn assert_fmt_eq_contract<T: FmtEq>(samples: &[T]) {
+    for a in samples {
+        for b in samples {
+            let (eq, fmt_eq) = (a == b, generic_eq(a, b));
+            if eq != fmt_eq {
+                panic!(
+                    "`FmtEq` contract violated: `a == b` is `{}` but the representations are {}: \
+                     a = `{}`, b = `{}`",
+                    eq,
+                    if fmt_eq { "equal" } else { "not equal" },
+                    a,
+                    b,
+                );
+            }
+        }
+    }
+}
+
+/// Asserts that every pair of `samples` satisfies the [`FmtOrd`] contract, i.e., `a.cmp(b)` is
+/// the same as the comparison of the `Display` representations of `a` and `b`.
+///
+/// This also checks the [`FmtEq`] contract like [`assert_fmt_eq_contract`] does.
+///
+/// ## Panics
+///
+/// Panics if any pair of `samples` (including a sample and itself) violates the contract.
+pub fn assert_fmt_ord_contract<T: FmtOrd>(samples: &[T]) {
+    assert_fmt_eq_contract(samples);
+    for a in samples {
+        for b in samples {
+            let (ord, fmt_ord) = (a.cmp(b), generic_cmp(a, b));
+            if ord != fmt_ord {
+                panic!(
+                    "`FmtOrd` contract violated: `a.cmp(b)` is `{:?}` but the representations \
+                     compare as `{:?}`: a = `{}`, b = `{}`",
+                    ord, fmt_ord, a, b,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::{self, Display, Formatter};
+    use std::num::Wrapping;
+
+    use super::*;
+    use crate::Cmp;
+
+    /// An integer that claims `FmtOrd` while being ordered numerically.
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    struct Numeric(u32);
+
+    impl Display for Numeric {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            Display::fmt(&self.0, f)
+        }
+    }
+
+    impl FmtEq for Numeric {}
+    impl FmtOrd for Numeric {}
+
+    #[test]
+    fn valid_impls() {
+        assert_fmt_ord_contract(&["", "a", "ab", "b", "\u{e9}", "\u{10000}"]);
+        assert_fmt_ord_contract(&[Wrapping("42"), Wrapping("240")]);
+        assert_fmt_eq_contract(&[Cmp(0.0), Cmp(-0.0), Cmp(f64::NAN), Cmp(f64::NAN)]);
+        assert_fmt_eq_contract(&[0_u32, 1, 10, 42, 240]);
+        // `Numeric` is fine as long as the samples are ordered the same either way.
+        assert_fmt_ord_contract(&[Numeric(1), Numeric(2), Numeric(3)]);
+        assert_fmt_ord_contract::<&str>(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`FmtOrd` contract violated: `a.cmp(b)` is `Less` but the \
+                               representations compare as `Greater`: a = `42`, b = `240`")]
+    fn invalid_fmt_ord() {
+        assert_fmt_ord_contract(&[Numeric(42), Numeric(240)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`FmtEq` contract violated: `a == b` is `false` but the \
+                               representations are equal: a = `x`, b = `x`")]
+    fn invalid_fmt_eq() {
+        /// Compares by the inner value but displays only the first field.
+        #[derive(PartialEq, Eq)]
+        struct Tagged(&'static str, u32);
+
+        impl Display for Tagged {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        impl FmtEq for Tagged {}
+
+        assert_fmt_eq_contract(&[Tagged("x", 1), Tagged("x", 2)]);
+    }
+}
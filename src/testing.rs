@@ -0,0 +1,67 @@
+//! Fixtures shared by the unit tests.
+
+#[cfg(not(feature = "alloc"))]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use std::fmt::{self, Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
+
+/// Displays only the first field, so that the values differing only in the second field have the
+/// same representation.
+#[derive(Debug, PartialEq)]
+pub struct Key(pub u32, pub u32);
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// Writes the string one `char` per chunk, with empty chunks in between.
+pub struct Chars<'a>(pub &'a str);
+
+impl Display for Chars<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.chars().try_for_each(|c| {
+            f.write_char(c)?;
+            f.write_str("")
+        })
+    }
+}
+
+/// A `Hasher` that records the calls to `Hasher::write` as they are.
+///
+/// `finish` returns the FNV-1a hash of the bytes written so far, which doesn't depend on how the
+/// bytes are split into the calls.
+#[derive(Default)]
+pub struct Recorder(pub Vec<Vec<u8>>);
+
+impl Recorder {
+    /// Returns all the bytes written so far.
+    pub fn bytes(&self) -> Vec<u8> {
+        self.0.concat()
+    }
+}
+
+impl Hasher for Recorder {
+    fn finish(&self) -> u64 {
+        self.0
+            .iter()
+            .flatten()
+            .fold(0xcbf2_9ce4_8422_2325, |h, &b| {
+                (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.push(bytes.to_vec());
+    }
+}
+
+/// Returns the bytes that `value` feeds to a `Hasher`.
+pub fn hash_of<T: Hash + ?Sized>(value: &T) -> Vec<u8> {
+    let mut hasher = Recorder::default();
+    value.hash(&mut hasher);
+    hasher.bytes()
+}
@@ -0,0 +1,45 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::hash::Hasher;
+
+/// An extension trait providing the method syntax for comparing values in their `Display`
+/// representations.
+///
+/// This is implemented for every `Display` type, including unsized ones like `str` and
+/// `dyn Display`. The methods are equivalent to the free functions [`cmp`](crate::cmp()),
+/// [`eq`](crate::eq()) and [`hash`](crate::hash()).
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::DisplayCompareExt;
+///
+/// assert!(42.fmt_cmp(&240).is_gt());
+/// assert!("abc".fmt_eq(&format_args!("ab{}", 'c')));
+/// ```
+pub trait DisplayCompareExt: Display {
+    /// Compares `self` with `other` in their `Display` representations.
+    ///
+    /// This is the same as [`cmp(self, other)`](crate::cmp()).
+    #[must_use]
+    fn fmt_cmp<U: Display + ?Sized>(&self, other: &U) -> Ordering {
+        crate::cmp(self, other)
+    }
+
+    /// Tests `self` and `other` for equality in their `Display` representations.
+    ///
+    /// This is the same as [`eq(self, other)`](crate::eq()).
+    #[must_use]
+    fn fmt_eq<U: Display + ?Sized>(&self, other: &U) -> bool {
+        crate::eq(self, other)
+    }
+
+    /// Hashes `self` with respect to its `Display` representation.
+    ///
+    /// This is the same as [`hash(self, state)`](crate::hash()).
+    fn fmt_hash<H: Hasher>(&self, state: &mut H) {
+        crate::hash(self, state)
+    }
+}
+
+impl<T: Display + ?Sized> DisplayCompareExt for T {}
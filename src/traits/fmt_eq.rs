@@ -1,6 +1,10 @@
 use std::borrow::Borrow;
 use std::convert::Infallible;
 use std::fmt::Display;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
 use std::ops::Deref;
 use std::pin::Pin;
 
@@ -59,6 +63,13 @@ impl FmtEq for bool {}
 
 impl FmtEq for Infallible {}
 
+// `Wrapping<T>` displays and compares the same as its inner value. `Saturating<T>` does as well, but
+// it is not available in our MSRV.
+impl<T: FmtEq> FmtEq for Wrapping<T> {}
+
+// `ManuallyDrop<T>` and `cmp::Reverse<T>` forward `PartialEq` to the inner value, but they don't
+// implement `Display`, so they cannot implement `FmtEq` (wrap the inner value in `Cmp` instead).
+
 // `alloc` types.
 #[cfg(feature = "alloc")]
 impl<T: FmtEq + ?Sized> FmtEq for alloc::boxed::Box<T> {}
@@ -92,4 +103,35 @@ impl FmtEq for i64 {}
 impl FmtEq for i128 {}
 impl FmtEq for isize {}
 
+// `NonZero*` types display and compare the same as their inner integers.
+impl FmtEq for NonZeroU8 {}
+impl FmtEq for NonZeroU16 {}
+impl FmtEq for NonZeroU32 {}
+impl FmtEq for NonZeroU64 {}
+impl FmtEq for NonZeroU128 {}
+impl FmtEq for NonZeroUsize {}
+impl FmtEq for NonZeroI8 {}
+impl FmtEq for NonZeroI16 {}
+impl FmtEq for NonZeroI32 {}
+impl FmtEq for NonZeroI64 {}
+impl FmtEq for NonZeroI128 {}
+impl FmtEq for NonZeroIsize {}
+
+// IP addresses have a unique textual representation each, but the dotted-decimal notation does
+// not sort numerically (e.g. `"10.0.0.1" < "9.0.0.1"`). `SocketAddrV6` is not `FmtEq` since its
+// `flowinfo` is compared but not displayed.
+//
+// Without `std` feature, `std` refers to `core`, whose `net` module is available since Rust 1.77
+// (detected by the build script). `std::net` re-exports the same types in that case.
+#[cfg(any(feature = "std", fmt_cmp_core_net))]
+impl FmtEq for std::net::IpAddr {}
+#[cfg(any(feature = "std", fmt_cmp_core_net))]
+impl FmtEq for std::net::Ipv4Addr {}
+#[cfg(any(feature = "std", fmt_cmp_core_net))]
+impl FmtEq for std::net::Ipv6Addr {}
+
+// `Duration` cannot be `FmtEq` since it implements `Debug` but not `Display`. Its `Debug`
+// representation (e.g. `1.5s`) is unique for each value, but the unit suffixes make it sort
+// differently from the durations (e.g. `"2ms" > "1s"`), so it wouldn't be `FmtOrd` either.
+
 // TODO: Does `char` satisfy the trait contract?
@@ -1,6 +1,7 @@
 use std::borrow::Borrow;
 use std::convert::Infallible;
 use std::fmt::Display;
+use std::num::Wrapping;
 use std::ops::Deref;
 use std::pin::Pin;
 
@@ -37,6 +38,17 @@ use super::FmtEq;
 /// assert!(42.to_string() > 240.to_string());
 /// ```
 ///
+/// Neither do IP addresses, although they implement [`FmtEq`]:
+///
+#[cfg_attr(feature = "std", doc = " ```")]
+#[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+/// use std::net::Ipv4Addr;
+///
+/// assert!(Ipv4Addr::new(9, 0, 0, 1) < Ipv4Addr::new(10, 0, 0, 1));
+/// // but...
+/// assert!(fmt_cmp::Cmp(Ipv4Addr::new(9, 0, 0, 1)) > fmt_cmp::Cmp(Ipv4Addr::new(10, 0, 0, 1)));
+/// ```
+///
 /// Wrapping any `Display` type with [`fmt_cmp::Cmp`](crate::Cmp) makes it `FmtOrd`:
 ///
 /// ```
@@ -55,6 +67,12 @@ impl FmtOrd for bool {}
 
 impl FmtOrd for Infallible {}
 
+// This doesn't cover `Wrapping<u32>` and the like since the integers are `!FmtOrd`.
+impl<T: FmtOrd> FmtOrd for Wrapping<T> {}
+
+// `cmp::Reverse<T>` doesn't implement `Display`, but it would not be `FmtOrd` even if it did with
+// the inner value's representation, since it reverses the ordering of `T`.
+
 // `alloc` types.
 #[cfg(feature = "alloc")]
 impl<T: FmtOrd + ?Sized> FmtOrd for alloc::boxed::Box<T> {}
@@ -0,0 +1,80 @@
+/// Implements [`FmtEq`](crate::FmtEq) and [`FmtOrd`](crate::FmtOrd) marker traits for a type.
+///
+/// This is a declarative alternative to the derive macros, which is available without `derive`
+/// feature and can implement the traits for types with arbitrary generic bounds.
+///
+/// The macro accepts either a type, or `impl[<generic parameters>] <type>` followed by an optional
+/// `where` clause. Note that the generic parameters are enclosed in square brackets instead of
+/// angle brackets.
+///
+/// The generated implementations require the type to implement `Display` and `Ord`, so forgetting
+/// either of them results in a compile error. Like the derive macros, the macro cannot verify the
+/// semantic contract of the traits, i.e., that the ordering of the type matches that of its
+/// `Display` representation. It is still your responsibility to uphold the contract.
+///
+/// ## Examples
+///
+/// ```
+/// use std::fmt::{self, Display, Formatter};
+///
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// enum Fruit {
+///     Apple,
+///     Banana,
+/// }
+///
+/// impl Display for Fruit {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         f.write_str(match *self {
+///             Fruit::Apple => "apple",
+///             Fruit::Banana => "banana",
+///         })
+///     }
+/// }
+///
+/// fmt_cmp::fmt_ord!(Fruit);
+///
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// struct Basket<T>(T);
+///
+/// impl<T: Display> Display for Basket<T> {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         self.0.fmt(f)
+///     }
+/// }
+///
+/// fmt_cmp::fmt_ord!(impl[T] Basket<T> where T: fmt_cmp::FmtOrd);
+///
+/// fn assert_fmt_ord<T: fmt_cmp::FmtOrd>() {}
+/// assert_fmt_ord::<Fruit>();
+/// assert_fmt_ord::<Basket<Fruit>>();
+/// ```
+///
+/// A type without `Ord` implementation is rejected:
+///
+/// ```compile_fail
+/// use std::fmt::{self, Display, Formatter};
+///
+/// #[derive(PartialEq, Eq)]
+/// enum Fruit {
+///     Apple,
+/// }
+///
+/// impl Display for Fruit {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         f.write_str("apple")
+///     }
+/// }
+///
+/// fmt_cmp::fmt_ord!(Fruit);
+/// ```
+#[macro_export]
+macro_rules! fmt_ord {
+    (impl [$($generics:tt)*] $ty:ty $(where $($bounds:tt)*)?) => {
+        impl<$($generics)*> $crate::FmtEq for $ty $(where $($bounds)*)? {}
+        impl<$($generics)*> $crate::FmtOrd for $ty $(where $($bounds)*)? {}
+    };
+    ($ty:ty) => {
+        $crate::fmt_ord!(impl[] $ty);
+    };
+}
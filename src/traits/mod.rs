@@ -1,5 +1,200 @@
+mod ext;
 mod fmt_eq;
 mod fmt_ord;
+mod macros;
 
+pub use self::ext::DisplayCompareExt;
 pub use self::fmt_eq::FmtEq;
 pub use self::fmt_ord::FmtOrd;
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "alloc"))]
+    extern crate alloc;
+
+    use alloc::string::ToString;
+    use std::num::{NonZeroI32, NonZeroU32, Wrapping};
+
+    use super::*;
+    use crate::Cmp;
+
+    #[test]
+    fn non_zero() {
+        #[track_caller]
+        fn check<T: FmtEq + Ord>(x: T, y: T) {
+            assert_eq!(x == y, x.to_string() == y.to_string());
+            assert_eq!(Cmp(&x).cmp(&Cmp(&y)), x.to_string().cmp(&y.to_string()));
+        }
+
+        let (x, y) = (NonZeroU32::new(42).unwrap(), NonZeroU32::new(240).unwrap());
+        // `NonZero*` types are `!FmtOrd` like the plain integers.
+        assert!(x < y);
+        assert!(Cmp(x) > Cmp(y));
+        check(x, y);
+        check(x, x);
+
+        let (x, y) = (NonZeroI32::new(-42).unwrap(), NonZeroI32::new(-3).unwrap());
+        assert!(x < y);
+        assert!(Cmp(x) > Cmp(y));
+        check(x, y);
+        check(x, x);
+    }
+
+    #[test]
+    fn wrapping() {
+        fn assert_fmt_eq<T: FmtEq>(_: &T) {}
+        fn assert_fmt_ord<T: FmtOrd>(_: &T) {}
+
+        let (x, y) = (Wrapping(42_u32), Wrapping(240_u32));
+        assert_fmt_eq(&x);
+        assert!(x < y);
+        assert!(Cmp(x) > Cmp(y));
+        assert_eq!(Cmp(x), Cmp(Wrapping(42)));
+        assert_eq!(Cmp(x), Cmp(42_u32));
+        assert_eq!(Cmp(x).cmp(&Cmp(y)), x.to_string().cmp(&y.to_string()));
+
+        let (x, y) = (Wrapping("42"), Wrapping("240"));
+        assert_fmt_ord(&x);
+        assert_eq!(x.cmp(&y), Cmp(x).cmp(&Cmp(y)));
+    }
+
+    #[test]
+    fn duration_debug() {
+        use std::time::Duration;
+
+        use alloc::format;
+
+        fn cmp_debug(x: Duration, y: Duration) -> std::cmp::Ordering {
+            crate::cmp(&format_args!("{:?}", x), &format_args!("{:?}", y))
+        }
+
+        let values = [
+            Duration::from_nanos(1),
+            Duration::from_micros(1),
+            Duration::from_millis(2),
+            Duration::from_millis(1500),
+            Duration::from_secs(1),
+            Duration::from_secs(1) + Duration::from_nanos(1),
+            Duration::from_secs(10),
+        ];
+        for &x in &values {
+            for &y in &values {
+                assert_eq!(x == y, format!("{:?}", x) == format!("{:?}", y));
+            }
+        }
+
+        // The lexical order of the `Debug` representations differs from the temporal order.
+        let (x, y) = (Duration::from_millis(2), Duration::from_secs(1));
+        assert!(x < y);
+        assert!(cmp_debug(x, y).is_gt()); // `"2ms" > "1s"`
+        let (x, y) = (Duration::from_secs(9), Duration::from_secs(10));
+        assert!(x < y);
+        assert!(cmp_debug(x, y).is_gt()); // `"9s" > "10s"`
+    }
+
+    #[test]
+    fn fmt_ord_macro() {
+        use std::fmt::{self, Display, Formatter};
+
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        enum Level {
+            Debug,
+            Error,
+            Info,
+        }
+
+        impl Display for Level {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str(match *self {
+                    Level::Debug => "debug",
+                    Level::Error => "error",
+                    Level::Info => "info",
+                })
+            }
+        }
+
+        crate::fmt_ord!(Level);
+
+        #[derive(PartialEq, Eq, PartialOrd, Ord)]
+        struct Tagged<'a, T>(&'a str, T);
+
+        impl<T: Display> Display for Tagged<'_, T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "{}{}", self.0, self.1)
+            }
+        }
+
+        // Not actually `FmtOrd` in general, but good enough for checking the macro expansion.
+        crate::fmt_ord!(impl['a, T: FmtOrd] Tagged<'a, T> where T: Copy,);
+
+        #[track_caller]
+        fn check<T: FmtOrd>(x: T, y: T) {
+            assert_eq!(x.cmp(&y), x.to_string().cmp(&y.to_string()));
+            assert_eq!(Cmp(&x).cmp(&Cmp(&y)), x.cmp(&y));
+        }
+
+        let levels = [Level::Debug, Level::Error, Level::Info];
+        for &x in &levels {
+            for &y in &levels {
+                check(x, y);
+                check(Tagged("level=", x), Tagged("level=", y));
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", fmt_cmp_core_net))]
+    #[test]
+    fn ip_addr() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        #[track_caller]
+        fn check<T: FmtEq>(x: T, y: T) {
+            assert_eq!(x == y, x.to_string() == y.to_string());
+        }
+
+        let (x, y) = (Ipv4Addr::new(9, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 1));
+        // Lexicographic ordering of the dotted-decimal notation differs from the numeric ordering,
+        // so IP addresses are `!FmtOrd`.
+        assert!(x < y);
+        assert!(Cmp(x) > Cmp(y));
+        check(x, y);
+        check(x, x);
+
+        let (x, y) = (
+            Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1),
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+        );
+        assert!(x < y);
+        assert!(Cmp(x) > Cmp(y));
+        check(x, y);
+        check(x, x);
+
+        // An IPv4 address and its IPv4-mapped IPv6 address are distinguished.
+        let (x, y) = (
+            IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+            IpAddr::V6(Ipv4Addr::new(192, 0, 2, 1).to_ipv6_mapped()),
+        );
+        check(x, y);
+        check(x, x);
+    }
+
+    #[test]
+    fn display_compare_ext() {
+        use std::cmp::Ordering;
+        use std::fmt::Display;
+
+        use crate::testing::Recorder;
+
+        let dyn_display: &dyn Display = &42;
+        assert_eq!(dyn_display.fmt_cmp(&240), Ordering::Greater);
+        assert_eq!("42".fmt_cmp(dyn_display), Ordering::Equal);
+        assert!(dyn_display.fmt_eq("42"));
+        assert!(!"abc".fmt_eq(&format_args!("ab{}", 'd')));
+        assert_eq!(Cmp(42).fmt_cmp(&Cmp(240)), Ordering::Greater);
+
+        let (mut a, mut b) = (Recorder::default(), Recorder::default());
+        dyn_display.fmt_hash(&mut a);
+        "42".fmt_hash(&mut b);
+        assert_eq!(a.bytes(), b.bytes());
+    }
+}
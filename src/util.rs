@@ -0,0 +1,176 @@
+//! Miscellaneous utilities for processing `Display` representations without heap allocation.
+
+use std::fmt::{self, Display, Formatter, Write};
+use std::str;
+
+/// Writes the `Display` representation of a value into a byte buffer and returns the written
+/// string.
+///
+/// This is useful for inspecting the `Display` representation of a value in a `no_std`
+/// environment without heap allocation.
+///
+/// ## Errors
+///
+/// Returns an error if the representation does not fit in `buf` or if the `Display`
+/// implementation returns an error. In that case, the contents of `buf` are unspecified.
+///
+/// ## Example
+///
+/// ```
+/// let mut buf = [0_u8; 8];
+/// assert_eq!(fmt_cmp::util::fmt_into(&mut buf, &42), Ok("42"));
+/// assert!(fmt_cmp::util::fmt_into(&mut buf, &u64::MAX).is_err());
+/// ```
+pub fn fmt_into<'a, T: Display + ?Sized>(
+    buf: &'a mut [u8],
+    value: &T,
+) -> Result<&'a str, fmt::Error> {
+    struct Adapter<'a> {
+        buf: &'a mut [u8],
+        pos: usize,
+    }
+
+    impl Write for Adapter<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.pos.checked_add(s.len()).ok_or(fmt::Error)?;
+            // Write the whole chunk or nothing, so that `buf[..pos]` never ends with a partial
+            // `char`.
+            let dst = self.buf.get_mut(self.pos..end).ok_or(fmt::Error)?;
+            dst.copy_from_slice(s.as_bytes());
+            self.pos = end;
+            Ok(())
+        }
+    }
+
+    let mut adapter = Adapter { buf, pos: 0 };
+    write!(adapter, "{}", value)?;
+    let Adapter { buf, pos } = adapter;
+    // Safety: `buf[..pos]` consists of whole `str` chunks written by `Adapter::write_str`.
+    Ok(unsafe { str::from_utf8_unchecked(&buf[..pos]) })
+}
+
+/// A `Display` adapter that writes the `char`s yielded by an iterator.
+///
+/// This lets you compare the output of a `char` iterator, like a (possibly filtered)
+/// `str::Chars`, as a string without collecting it into a `String`.
+///
+/// ## Note
+///
+/// `Display::fmt` takes `&self`, so each call of it iterates over a clone of the inner iterator.
+/// Since the functions in this crate may call `Display::fmt` multiple times for a single
+/// comparison, the clones of the iterator must yield the same sequence every time, which is the
+/// case for the iterator adapters in the standard library as long as the closures are
+/// deterministic.
+///
+/// ## Example
+///
+/// ```
+/// use fmt_cmp::util::DisplayChars;
+///
+/// let s = "f-o-o";
+/// assert!(fmt_cmp::eq(&DisplayChars(s.chars().filter(|&c| c != '-')), "foo"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayChars<I>(pub I);
+
+impl<I: Iterator<Item = char> + Clone> Display for DisplayChars<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Buffer the `char`s to avoid writing them to the `Formatter` one by one.
+        let mut buf = [0_u8; 64];
+        let mut len = 0;
+        for c in self.0.clone() {
+            if buf.len() - len < c.len_utf8() {
+                // Safety: `buf[..len]` consists of whole `char`s encoded by `char::encode_utf8`.
+                f.write_str(unsafe { str::from_utf8_unchecked(&buf[..len]) })?;
+                len = 0;
+            }
+            len += c.encode_utf8(&mut buf[len..]).len();
+        }
+        // Safety: Ditto.
+        f.write_str(unsafe { str::from_utf8_unchecked(&buf[..len]) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_fit() {
+        let mut buf = [0_u8; 5];
+        assert_eq!(fmt_into(&mut buf, "hello"), Ok("hello"));
+        assert_eq!(fmt_into(&mut buf, &format_args!("{}{}", 4, 2)), Ok("42"));
+        assert_eq!(fmt_into(&mut buf, ""), Ok(""));
+        assert_eq!(fmt_into(&mut [], ""), Ok(""));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut buf = [0_u8; 5];
+        assert_eq!(fmt_into(&mut buf, "hello!"), Err(fmt::Error));
+        assert_eq!(
+            fmt_into(&mut buf, &format_args!("{}{}", "hell", "o!")),
+            Err(fmt::Error)
+        );
+        assert_eq!(fmt_into(&mut [], "a"), Err(fmt::Error));
+    }
+
+    #[test]
+    fn multi_byte_char() {
+        // `"é"` is 2 bytes long in UTF-8.
+        let mut buf = [0_u8; 5];
+        assert_eq!(fmt_into(&mut buf, "abcé"), Ok("abcé"));
+        assert_eq!(fmt_into(&mut buf, "abcdé"), Err(fmt::Error));
+        assert_eq!(fmt_into(&mut buf, "ééé"), Err(fmt::Error));
+        assert_eq!(fmt_into(&mut buf[..4], "éé"), Ok("éé"));
+        assert_eq!(fmt_into(&mut buf[..3], "éé"), Err(fmt::Error));
+    }
+
+    #[test]
+    fn display_error() {
+        struct Failing;
+
+        impl Display for Failing {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a")?;
+                Err(fmt::Error)
+            }
+        }
+
+        assert_eq!(fmt_into(&mut [0_u8; 8], &Failing), Err(fmt::Error));
+    }
+
+    #[test]
+    fn display_chars() {
+        #[cfg(not(feature = "alloc"))]
+        extern crate alloc;
+
+        use alloc::string::{String, ToString};
+        use std::cmp::Ordering;
+
+        #[track_caller]
+        fn check<I: Iterator<Item = char> + Clone>(chars: I, other: &str) {
+            let expected: String = chars.clone().collect();
+            let chars = DisplayChars(chars);
+            assert_eq!(chars.to_string(), expected);
+            assert_eq!(crate::cmp(&chars, other), expected.as_str().cmp(other));
+            assert_eq!(crate::cmp(other, &chars), other.cmp(&expected));
+            assert_eq!(crate::eq(&chars, other), expected == other);
+        }
+
+        let s = "f-o-o b-a-r";
+        check(s.chars().filter(|&c| c != '-'), "foo bar");
+        check(s.chars().filter(|&c| c != '-'), "foo");
+        check(s.chars().filter(|&c| c != '-'), "foo baz");
+        check(s.chars().rev(), "r-a-b o-o-f");
+        check("".chars(), "");
+        check("".chars(), "a");
+        check("é".repeat(100).chars().step_by(2), &"é".repeat(50));
+        check("é".repeat(100).chars().step_by(2), &"é".repeat(51));
+
+        assert_eq!(
+            crate::cmp(&DisplayChars("b-a".chars().filter(|&c| c != '-')), "ab"),
+            Ordering::Greater
+        );
+    }
+}
@@ -0,0 +1,83 @@
+#![no_std]
+#![no_main]
+
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter, Write};
+use core::hash::Hasher;
+use core::net::Ipv4Addr;
+use core::panic::PanicInfo;
+
+#[link(name = "c")]
+extern "C" {
+    fn abort() -> !;
+}
+
+#[panic_handler]
+fn panic(_: &PanicInfo<'_>) -> ! {
+    unsafe { abort() }
+}
+
+// The precompiled `core` refers to the personality routine even with `panic = "abort"`, although
+// it is never called.
+#[no_mangle]
+extern "C" fn rust_eh_personality() {}
+
+/// The 64-bit FNV-1a hash function.
+struct Fnv(u64);
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x100_0000_01b3);
+        }
+    }
+}
+
+fn hash<T: Display + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+    fmt_cmp::hash(value, &mut hasher);
+    hasher.finish()
+}
+
+fn fmt_eq<T: fmt_cmp::FmtEq>(x: &T, y: &T) -> bool {
+    fmt_cmp::eq(x, y)
+}
+
+/// Writes the string one `char` at a time.
+struct Chars(&'static str);
+
+impl Display for Chars {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.chars().try_for_each(|c| f.write_char(c))
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn main(_argc: i32, _argv: *const *const u8) -> i32 {
+    assert_eq!(fmt_cmp::cmp(&42_u32, &240_u32), Ordering::Greater);
+    assert_eq!(fmt_cmp::cmp(&Chars("42"), "240"), Ordering::Greater);
+    assert!(fmt_cmp::eq(&Chars("42"), &42));
+    assert!(fmt_cmp::Cmp(42_u32) > fmt_cmp::Cmp(240_u32));
+
+    assert_eq!(fmt_cmp::cmp_dec(42_u32, 240_u32), Ordering::Greater);
+    assert_eq!(fmt_cmp::cmp_dec(-1_i64, 0_i64), Ordering::Less);
+    assert_eq!(fmt_cmp::cmp_int(0xff_u16, 0x100_u16, 16), Ordering::Greater);
+
+    assert_eq!(hash(&42_u32), hash("42"));
+    assert_eq!(hash(&Chars("foo")), hash("foo"));
+    assert_ne!(hash("foo"), hash("bar"));
+
+    // `core::net` types are `FmtEq` without `std` (Rust 1.77+).
+    let (x, y) = (Ipv4Addr::new(9, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 1));
+    assert_eq!(fmt_eq(&x, &y), x == y);
+    assert!(fmt_eq(&x, &x));
+    assert!(fmt_cmp::Cmp(x) == fmt_cmp::Cmp(x));
+    assert!(fmt_cmp::Cmp(x) != fmt_cmp::Cmp(y));
+    assert!(fmt_cmp::Cmp(x) > fmt_cmp::Cmp(y));
+
+    0
+}
@@ -0,0 +1,204 @@
+//! Property tests checking the comparison functions against the comparison of `to_string()`.
+//!
+//! Run with `cargo test --features proptest-tests`. The number of cases per property can be
+//! increased with `PROPTEST_CASES` environment variable, e.g., `PROPTEST_CASES=1000000`.
+
+#![cfg(feature = "proptest-tests")]
+
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hasher;
+
+use proptest::prelude::*;
+
+/// Writes the string in chunks whose lengths (in characters) are taken cyclically from the second
+/// field, with an empty chunk between each pair of chunks.
+#[derive(Clone, Debug)]
+struct SplitFmt(String, Vec<usize>);
+
+impl Display for SplitFmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let SplitFmt(ref s, ref lens) = *self;
+        let mut rest = &s[..];
+        for &n in lens.iter().cycle() {
+            if rest.is_empty() {
+                break;
+            }
+            let i = rest.char_indices().nth(n).map_or(rest.len(), |(i, _)| i);
+            let (chunk, tail) = rest.split_at(i);
+            f.write_str(chunk)?;
+            f.write_str("")?;
+            rest = tail;
+        }
+        f.write_str(rest)
+    }
+}
+
+/// Strings that are likely to share long prefixes with each other.
+fn string() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[ab]{0,80}",
+        "[0-9]{0,24}",
+        ".{0,16}",
+        "(abc|ab|é|\u{e9}\u{301}|\u{10000}){0,24}",
+    ]
+}
+
+fn split_fmt() -> impl Strategy<Value = SplitFmt> {
+    (string(), prop::collection::vec(1..8_usize, 1..4)).prop_map(|(s, lens)| SplitFmt(s, lens))
+}
+
+/// Pairs of a value and a shared-prefix variant of it.
+fn string_pair() -> impl Strategy<Value = (String, String)> {
+    prop_oneof![
+        (string(), string()),
+        (string(), string()).prop_map(|(prefix, suffix)| {
+            let other = format!("{}{}", prefix, suffix);
+            (prefix, other)
+        }),
+    ]
+}
+
+fn hash_of<T: Display + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    fmt_cmp::hash(value, &mut hasher);
+    hasher.finish()
+}
+
+fn to_radix(value: u128, radix: u32) -> String {
+    if value == 0 {
+        return "0".to_owned();
+    }
+    let mut digits = Vec::new();
+    let mut value = value;
+    while value > 0 {
+        let d = (value % u128::from(radix)) as u32;
+        digits.push(std::char::from_digit(d, radix).unwrap());
+        value /= u128::from(radix);
+    }
+    digits.iter().rev().collect()
+}
+
+/// Integers concentrated around powers of ten, where the digit alignment is most subtle.
+fn u64_near_pow10() -> impl Strategy<Value = u64> {
+    (0..20_u32, -2..=2_i64).prop_map(|(exp, offset)| {
+        let pow = 10_u64.pow(exp);
+        (pow as i64).wrapping_add(offset) as u64
+    })
+}
+
+fn u64_value() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        any::<u64>(),
+        u64_near_pow10(),
+        (0..1000_u64),
+        any::<u64>().prop_map(|x| x >> (x % 64))
+    ]
+}
+
+proptest! {
+    #[test]
+    fn cmp_matches_str_cmp((a, b) in string_pair(), lens in prop::collection::vec(1..8_usize, 1..4)) {
+        let expected = a.cmp(&b);
+        let (x, y) = (SplitFmt(a.clone(), lens.clone()), SplitFmt(b.clone(), lens));
+        prop_assert_eq!(fmt_cmp::cmp(&x, &y), expected);
+        prop_assert_eq!(fmt_cmp::cmp(&y, &x), expected.reverse());
+        prop_assert_eq!(fmt_cmp::cmp(&x, &*b), expected);
+        prop_assert_eq!(fmt_cmp::eq(&x, &y), a == b);
+        prop_assert_eq!(fmt_cmp::cmp::cmp_buffered(&x, &y), expected);
+    }
+
+    #[test]
+    fn cmp_with_different_chunks(x in split_fmt(), y in split_fmt()) {
+        let expected = x.0.cmp(&y.0);
+        prop_assert_eq!(fmt_cmp::cmp(&x, &y), expected);
+        prop_assert_eq!(fmt_cmp::eq(&x, &y), expected == Ordering::Equal);
+
+        let (ret, pos) = fmt_cmp::cmp_at(&x, &y);
+        prop_assert_eq!(ret, expected);
+        let (a, b) = (x.0.as_bytes(), y.0.as_bytes());
+        let expected_pos = a.iter().zip(b).take_while(|(a, b)| a == b).count();
+        prop_assert_eq!(pos, expected_pos);
+        let prefix_len = fmt_cmp::common_prefix_len(&x, &y);
+        prop_assert!(prefix_len <= expected_pos);
+        prop_assert!(x.0.is_char_boundary(prefix_len));
+        prop_assert_eq!(&x.0[..prefix_len], &y.0[..prefix_len]);
+    }
+
+    #[test]
+    fn hash_is_chunk_invariant(x in split_fmt()) {
+        prop_assert_eq!(hash_of(&x), hash_of(&*x.0));
+    }
+
+    #[test]
+    fn starts_ends_with((a, b) in string_pair(), lens in prop::collection::vec(1..8_usize, 1..4)) {
+        let (x, y) = (SplitFmt(a.clone(), lens.clone()), SplitFmt(b.clone(), lens));
+        prop_assert_eq!(fmt_cmp::cmp::display_starts_with(&y, &x), b.starts_with(&*a));
+        prop_assert_eq!(fmt_cmp::cmp::display_ends_with(&y, &x), b.ends_with(&*a));
+    }
+
+    #[test]
+    fn cmp_dec_matches_str_cmp(a in u64_value(), b in u64_value()) {
+        let expected = a.to_string().cmp(&b.to_string());
+        prop_assert_eq!(fmt_cmp::cmp_dec(a, b), expected);
+        prop_assert_eq!(fmt_cmp::cmp_int(a, b, 10), expected);
+        prop_assert_eq!(fmt_cmp::cmp(&a, &b), expected);
+    }
+
+    #[test]
+    fn cmp_dec_signed_matches_str_cmp(a in any::<i64>(), b in any::<i64>(), shift in 0..64_u32) {
+        let (a, b) = (a >> shift, b >> shift);
+        let expected = a.to_string().cmp(&b.to_string());
+        prop_assert_eq!(fmt_cmp::cmp_dec(a, b), expected);
+        prop_assert_eq!(fmt_cmp::cmp_int(a, b, 10), expected);
+    }
+
+    #[test]
+    fn cmp_dec_128_matches_str_cmp(a in any::<u128>(), b in any::<u128>(), shift in 0..128_u32) {
+        let (a, b) = (a >> shift, b >> (shift / 2));
+        prop_assert_eq!(fmt_cmp::cmp_dec(a, b), a.to_string().cmp(&b.to_string()));
+        let (a, b) = (a as i128, -(b as i128));
+        prop_assert_eq!(fmt_cmp::cmp_dec(a, b), a.to_string().cmp(&b.to_string()));
+    }
+
+    #[test]
+    fn cmp_dec_mixed_matches_str_cmp(a in any::<i16>(), b in u64_value()) {
+        let expected = a.to_string().cmp(&b.to_string());
+        prop_assert_eq!(fmt_cmp::int::cmp_dec_mixed(a, b), expected);
+        prop_assert_eq!(fmt_cmp::int::cmp_dec_mixed(b, a), expected.reverse());
+    }
+
+    #[test]
+    fn cmp_dec_padded_matches_str_cmp(a in any::<i32>(), b in any::<i32>(), shift in 0..32_u32, width in 0..14_usize) {
+        let (a, b) = (a >> shift, b >> shift);
+        let expected = format!("{:0w$}", a, w = width).cmp(&format!("{:0w$}", b, w = width));
+        prop_assert_eq!(fmt_cmp::int::cmp_dec_padded(a, b, width), expected);
+    }
+
+    #[test]
+    fn cmp_int_matches_str_cmp(a in u64_value(), b in u64_value(), radix in 2..=36_u32) {
+        let expected = to_radix(a.into(), radix).cmp(&to_radix(b.into(), radix));
+        prop_assert_eq!(fmt_cmp::cmp_int(a, b, radix), expected);
+    }
+
+    #[test]
+    fn cmp_hex_oct_bin_match_str_cmp(a in any::<i64>(), b in any::<i64>(), shift in 0..64_u32) {
+        let (a, b) = (a >> shift, b >> shift);
+        prop_assert_eq!(fmt_cmp::cmp_hex(a, b), format!("{:x}", a).cmp(&format!("{:x}", b)));
+        prop_assert_eq!(fmt_cmp::cmp_oct(a, b), format!("{:o}", a).cmp(&format!("{:o}", b)));
+        prop_assert_eq!(fmt_cmp::cmp_bin(a, b), format!("{:b}", a).cmp(&format!("{:b}", b)));
+    }
+
+    #[test]
+    fn sort_dec_matches_sort_by_str(mut values in prop::collection::vec(any::<i32>(), 0..64)) {
+        let mut expected = values.clone();
+        expected.sort_by_key(|v| v.to_string());
+        fmt_cmp::int::sort_dec(&mut values);
+        let (values, expected): (Vec<_>, Vec<_>) = (
+            values.iter().map(ToString::to_string).collect(),
+            expected.iter().map(ToString::to_string).collect(),
+        );
+        prop_assert_eq!(values, expected);
+    }
+}